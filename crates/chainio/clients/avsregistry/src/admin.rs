@@ -0,0 +1,487 @@
+use crate::error::AvsRegistryError;
+use alloy_primitives::{Address, Bytes, U256};
+use eigen_client_eth::chain::verify_chain_and_contracts;
+use eigen_client_eth::outcome::{send_with_outcome, TxOutcome};
+use eigen_client_eth::wallet::{PrivateKeyWallet, TxSender};
+use eigen_types::quorum::{OperatorSetParams, StrategyParams};
+use eigen_utils::{
+    binding::{RegistryCoordinator, StakeRegistry},
+    get_provider,
+};
+use std::fmt::Debug;
+use std::sync::Arc;
+use tracing::info;
+
+/// Owner and ejector side of the AVS registry contracts: quorum creation and
+/// configuration, churn approver and ejector management, and operator ejection.
+///
+/// The transactions are sent by the owner of the registry coordinator, or its ejector
+/// for [`AvsRegistryAdminWriter::eject_operator`].
+#[derive(Debug)]
+pub struct AvsRegistryAdminWriter {
+    registry_coordinator_addr: Address,
+    stake_registry_addr: Address,
+    provider: String,
+    tx_sender: Arc<dyn TxSender>,
+}
+
+impl AvsRegistryAdminWriter {
+    /// New AvsRegistryAdminWriter instance signing the transactions with `signer`
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. Every
+    /// given and resolved contract address is checked to have code deployed.
+    pub async fn new(
+        registry_coordinator_addr: Address,
+        provider: String,
+        signer: String,
+        expected_chain_id: Option<u64>,
+    ) -> Result<Self, AvsRegistryError> {
+        verify_chain_and_contracts(&provider, expected_chain_id, &[registry_coordinator_addr])
+            .await?;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(registry_coordinator_addr, get_provider(&provider));
+        let RegistryCoordinator::stakeRegistryReturn {
+            _0: stake_registry_addr,
+        } = contract_registry_coordinator
+            .stakeRegistry()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStakeRegistry))?;
+
+        verify_chain_and_contracts(&provider, None, &[stake_registry_addr]).await?;
+
+        Ok(AvsRegistryAdminWriter {
+            registry_coordinator_addr,
+            stake_registry_addr,
+            tx_sender: Arc::new(PrivateKeyWallet::new(signer, provider.clone())),
+            provider,
+        })
+    }
+
+    /// Sends the transactions through `tx_sender` instead of signing them with the
+    /// private key given to [`AvsRegistryAdminWriter::new`].
+    pub fn with_tx_sender(mut self, tx_sender: Arc<dyn TxSender>) -> Self {
+        self.tx_sender = tx_sender;
+        self
+    }
+
+    /// Create a quorum with `operator_set_params`, `minimum_stake` and weighted by
+    /// `strategies`. It gets the next quorum number, the current quorum count.
+    pub async fn create_quorum(
+        &self,
+        operator_set_params: OperatorSetParams,
+        minimum_stake: U256,
+        strategies: Vec<StrategyParams>,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(operator_set_params = ?operator_set_params, minimum_stake = %minimum_stake, strategies = ?strategies, "creating quorum");
+        let minimum_stake = uint96(minimum_stake)?;
+        let strategies = strategy_multipliers(strategies)?
+            .into_iter()
+            .map(
+                |(strategy, multiplier)| RegistryCoordinator::StrategyParams {
+                    strategy,
+                    multiplier,
+                },
+            )
+            .collect();
+
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.createQuorum(
+            operator_set_params.into(),
+            minimum_stake,
+            strategies,
+        );
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::CreateQuorum),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, "successfully created quorum");
+        Ok(outcome)
+    }
+
+    /// Set the operator cap and churn thresholds of `quorum_number`
+    pub async fn set_operator_set_params(
+        &self,
+        quorum_number: u8,
+        operator_set_params: OperatorSetParams,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(quorum_number, operator_set_params = ?operator_set_params, "setting operator set params");
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator
+            .setOperatorSetParams(quorum_number, operator_set_params.into());
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::SetOperatorSetParams),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, quorum_number, "successfully set operator set params");
+        Ok(outcome)
+    }
+
+    /// Set the address signing the churn approvals of all quorums
+    pub async fn set_churn_approver(
+        &self,
+        churn_approver: Address,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(churn_approver = %churn_approver, "setting churn approver");
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.setChurnApprover(churn_approver);
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::SetChurnApprover),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, churn_approver = %churn_approver, "successfully set churn approver");
+        Ok(outcome)
+    }
+
+    /// Set the address allowed to eject operators
+    pub async fn set_ejector(&self, ejector: Address) -> Result<TxOutcome, AvsRegistryError> {
+        info!(ejector = %ejector, "setting ejector");
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.setEjector(ejector);
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::SetEjector),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, ejector = %ejector, "successfully set ejector");
+        Ok(outcome)
+    }
+
+    /// Eject `operator` from `quorum_numbers`, sent by the ejector
+    pub async fn eject_operator(
+        &self,
+        operator: Address,
+        quorum_numbers: Bytes,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(operator = %operator, quorum_numbers = %quorum_numbers, "ejecting operator");
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call =
+            contract_registry_coordinator.ejectOperator(operator, quorum_numbers.clone());
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::EjectOperator),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, operator = %operator, quorum_numbers = %quorum_numbers, "successfully ejected operator");
+        Ok(outcome)
+    }
+
+    /// Add `strategies` to the strategies weighting the stake of `quorum_number`
+    pub async fn add_strategies(
+        &self,
+        quorum_number: u8,
+        strategies: Vec<StrategyParams>,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(quorum_number, strategies = ?strategies, "adding strategies");
+        let strategies = strategy_multipliers(strategies)?
+            .into_iter()
+            .map(|(strategy, multiplier)| StakeRegistry::StrategyParams {
+                strategy,
+                multiplier,
+            })
+            .collect();
+
+        let provider = get_provider(&self.provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call = contract_stake_registry.addStrategies(quorum_number, strategies);
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::AddStrategies),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, quorum_number, "successfully added strategies");
+        Ok(outcome)
+    }
+
+    /// Remove the strategies at `indices` from the strategies of `quorum_number`.
+    ///
+    /// The stake registry swaps each removed strategy with the last one, so the indices
+    /// are best given in descending order.
+    pub async fn remove_strategies(
+        &self,
+        quorum_number: u8,
+        indices: Vec<U256>,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(quorum_number, indices = ?indices, "removing strategies");
+        let provider = get_provider(&self.provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call = contract_stake_registry.removeStrategies(quorum_number, indices);
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::RemoveStrategies),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, quorum_number, "successfully removed strategies");
+        Ok(outcome)
+    }
+
+    /// Set the multipliers of the strategies at `strategy_indices` of `quorum_number`
+    /// to `new_multipliers`
+    pub async fn modify_strategy_params(
+        &self,
+        quorum_number: u8,
+        strategy_indices: Vec<U256>,
+        new_multipliers: Vec<U256>,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(quorum_number, strategy_indices = ?strategy_indices, new_multipliers = ?new_multipliers, "modifying strategy params");
+        let new_multipliers = new_multipliers
+            .into_iter()
+            .map(uint96)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let provider = get_provider(&self.provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call = contract_stake_registry.modifyStrategyParams(
+            quorum_number,
+            strategy_indices,
+            new_multipliers,
+        );
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::ModifyStrategyParams),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, quorum_number, "successfully modified strategy params");
+        Ok(outcome)
+    }
+
+    /// Set the stake an operator needs to register in `quorum_number`
+    pub async fn set_minimum_stake_for_quorum(
+        &self,
+        quorum_number: u8,
+        minimum_stake: U256,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(quorum_number, minimum_stake = %minimum_stake, "setting minimum stake for quorum");
+        let minimum_stake = uint96(minimum_stake)?;
+
+        let provider = get_provider(&self.provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call =
+            contract_stake_registry.setMinimumStakeForQuorum(quorum_number, minimum_stake);
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::SetMinimumStake),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, quorum_number, "successfully set minimum stake for quorum");
+        Ok(outcome)
+    }
+}
+
+/// Strategies of `strategies` with their multiplier as a uint96
+fn strategy_multipliers(
+    strategies: Vec<StrategyParams>,
+) -> Result<Vec<(Address, u128)>, AvsRegistryError> {
+    strategies
+        .into_iter()
+        .map(|params| Ok((params.strategy, uint96(params.multiplier)?)))
+        .collect()
+}
+
+/// `value` as the uint96 the stake registry stores stakes and multipliers in
+fn uint96(value: U256) -> Result<u128, AvsRegistryError> {
+    if value.bit_len() > 96 {
+        return Err(AvsRegistryError::Uint96Overflow(value));
+    }
+    Ok(value.to())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::{AvsRegistryChainReader, AvsRegistryReader};
+    use crate::{ANVIL_HTTP_URL, OWNER_PRIVATE_KEY};
+    use eigen_testing_utils::anvil_constants::{self, ANVIL_RPC_URL};
+
+    const OWNER_ADDRESS: Address =
+        alloy_primitives::address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+    async fn build_admin_writer_and_reader() -> (AvsRegistryAdminWriter, AvsRegistryChainReader) {
+        let registry_coordinator = anvil_constants::get_registry_coordinator_address().await;
+        let operator_state_retriever =
+            anvil_constants::get_operator_state_retriever_address().await;
+        let admin_writer = AvsRegistryAdminWriter::new(
+            registry_coordinator,
+            ANVIL_HTTP_URL.to_string(),
+            OWNER_PRIVATE_KEY.to_string(),
+            Some(31337),
+        )
+        .await
+        .unwrap();
+        let reader = AvsRegistryChainReader::new(
+            registry_coordinator,
+            operator_state_retriever,
+            ANVIL_HTTP_URL.to_string(),
+            Some(31337),
+        )
+        .await
+        .unwrap();
+        (admin_writer, reader)
+    }
+
+    #[tokio::test]
+    async fn test_quorum_admin_operations() {
+        let _owner = crate::ANVIL_OWNER_LOCK.lock().await;
+        let (admin_writer, reader) = build_admin_writer_and_reader().await;
+        let strategy = anvil_constants::get_erc20_mock_strategy().await;
+        let one_ether = U256::from(10).pow(U256::from(18));
+
+        let quorum_number = reader.get_quorum_count().await.unwrap();
+        let operator_set_params = OperatorSetParams {
+            max_operator_count: 10,
+            kick_bips_of_operator_stake: 15_000,
+            kick_bips_of_total_stake: 100,
+        };
+        let strategy_params = StrategyParams {
+            strategy,
+            multiplier: one_ether,
+        };
+        admin_writer
+            .create_quorum(operator_set_params, U256::from(1), vec![strategy_params])
+            .await
+            .unwrap();
+        let config = reader.get_quorum_config(quorum_number).await.unwrap();
+        assert_eq!(reader.get_quorum_count().await.unwrap(), quorum_number + 1);
+        assert_eq!(config.operator_set_params, operator_set_params);
+        assert_eq!(config.minimum_stake, U256::from(1));
+        assert_eq!(config.strategies, vec![strategy_params]);
+
+        let operator_set_params = OperatorSetParams {
+            max_operator_count: 20,
+            ..operator_set_params
+        };
+        admin_writer
+            .set_operator_set_params(quorum_number, operator_set_params)
+            .await
+            .unwrap();
+        admin_writer
+            .set_minimum_stake_for_quorum(quorum_number, U256::from(100))
+            .await
+            .unwrap();
+        admin_writer
+            .modify_strategy_params(
+                quorum_number,
+                vec![U256::ZERO],
+                vec![one_ether * U256::from(2)],
+            )
+            .await
+            .unwrap();
+        let config = reader.get_quorum_config(quorum_number).await.unwrap();
+        assert_eq!(config.operator_set_params, operator_set_params);
+        assert_eq!(config.minimum_stake, U256::from(100));
+        assert_eq!(config.strategies[0].multiplier, one_ether * U256::from(2));
+
+        admin_writer
+            .remove_strategies(quorum_number, vec![U256::ZERO])
+            .await
+            .unwrap();
+        let config = reader.get_quorum_config(quorum_number).await.unwrap();
+        assert!(config.strategies.is_empty());
+
+        admin_writer
+            .add_strategies(quorum_number, vec![strategy_params])
+            .await
+            .unwrap();
+        let config = reader.get_quorum_config(quorum_number).await.unwrap();
+        assert_eq!(config.strategies, vec![strategy_params]);
+    }
+
+    #[tokio::test]
+    async fn test_set_churn_approver_and_ejector() {
+        let _owner = crate::ANVIL_OWNER_LOCK.lock().await;
+        let (admin_writer, reader) = build_admin_writer_and_reader().await;
+        let registry_coordinator = RegistryCoordinator::new(
+            anvil_constants::get_registry_coordinator_address().await,
+            ANVIL_RPC_URL.clone(),
+        );
+        let new_address = Address::repeat_byte(1);
+
+        admin_writer.set_churn_approver(new_address).await.unwrap();
+        let config = reader.get_quorum_config(0).await.unwrap();
+        assert_eq!(config.churn_approver, new_address);
+
+        admin_writer.set_ejector(new_address).await.unwrap();
+        let RegistryCoordinator::ejectorReturn { _0: ejector } =
+            registry_coordinator.ejector().call().await.unwrap();
+        assert_eq!(ejector, new_address);
+        assert!(matches!(
+            admin_writer
+                .eject_operator(Address::repeat_byte(2), Bytes::from([0]))
+                .await,
+            Err(AvsRegistryError::NotEjector)
+        ));
+
+        // restore the roles of the deployment
+        admin_writer
+            .set_churn_approver(OWNER_ADDRESS)
+            .await
+            .unwrap();
+        admin_writer.set_ejector(OWNER_ADDRESS).await.unwrap();
+        assert!(matches!(
+            admin_writer
+                .eject_operator(Address::repeat_byte(2), Bytes::from([0]))
+                .await,
+            Err(AvsRegistryError::OperatorNotRegistered)
+        ));
+    }
+
+    #[test]
+    fn test_uint96() {
+        let max = (U256::from(1) << 96) - U256::from(1);
+        assert_eq!(uint96(max).unwrap(), (1u128 << 96) - 1);
+        assert!(matches!(
+            uint96(max + U256::from(1)),
+            Err(AvsRegistryError::Uint96Overflow(_))
+        ));
+    }
+}
@@ -1,3 +1,14 @@
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_sol_types::SolInterface;
+use alloy_transport::TransportError;
+use eigen_client_elcontracts::error::ElContractsError;
+use eigen_client_eth::chain::ChainError;
+use eigen_client_eth::outcome::TxReverted;
+use eigen_client_eth::simulation::{SimulatedRevert, SimulationError};
+use eigen_client_eth::wallet::WalletError;
+use eigen_types::metadata::MetadataError;
+use eigen_types::socket::SocketError;
+use eigen_utils::revert::revert_reason;
 use thiserror::Error;
 
 /// Error returned by AvsRegistry
@@ -5,67 +16,71 @@ use thiserror::Error;
 pub enum AvsRegistryError {
     /// Could not get bls apk registry addresss
     #[error("Failed to get bls apk registry address")]
-    GetBlsApkRegistry,
+    GetBlsApkRegistry(#[source] alloy_contract::Error),
 
     /// Failed to get quorum count
     #[error("Faield to get quorum count")]
-    GetQuorumCount,
+    GetQuorumCount(#[source] alloy_contract::Error),
 
     /// Failed to ger operator state
     #[error("Failed to get operator state")]
-    GetOperatorState,
+    GetOperatorState(#[source] alloy_contract::Error),
 
     /// Failed to get operator state with registry coordinator and operator id
     #[error("Failed to get oeprator state with registry coordinator and operator id ")]
-    GetOperatorStateWithRegistryCoordinatorAndOperatorId,
+    GetOperatorStateWithRegistryCoordinatorAndOperatorId(#[source] alloy_contract::Error),
 
     /// Failed to get current block number
     #[error("Failed to get current block number")]
-    GetBlockNumber,
+    GetBlockNumber(#[source] TransportError),
 
     /// block number overflow
     #[error("block number overflowed")]
     BlockNumberOverflow,
 
-    /// Operator stake in quourm at block
-    #[error("failed to get operator stake in quorum at block number")]
-    GetOperatorStakeInQuorumAtBlockNumber,
-
-    /// Operator stake in quorums at block operator id
-    #[error("Failed to get operator stake in quorums at block operator id ")]
-    GetOperatorStakeInQuorumAtBlockOperatorId,
-
-    /// Operator stake in quourm at block
-    #[error("failed to get operator stake in quorum at current block number")]
-    GetOperatorStakeInQuorumAtCurrentBlockNumber,
-
     /// Current Quorum bitmap
     #[error("Failed to get current quorum bitmap")]
-    GetCurrentQuorumBitmap,
+    GetCurrentQuorumBitmap(#[source] alloy_contract::Error),
 
     /// Current stake
     #[error("Failed to get current stake")]
-    GetCurrentStake,
+    GetCurrentStake(#[source] alloy_contract::Error),
+
+    /// Failed to get the stake history of an operator
+    #[error("Failed to get stake history")]
+    GetStakeHistory(#[source] alloy_contract::Error),
+
+    /// Failed to get the total stake history of a quorum
+    #[error("Failed to get total stake history")]
+    GetTotalStakeHistory(#[source] alloy_contract::Error),
+
+    /// Failed to get the stake at a block number and index
+    #[error("Failed to get stake at block number and index")]
+    GetStakeAtBlockNumberAndIndex(#[source] alloy_contract::Error),
 
     /// Check Signature indices result
     #[error("Check Signature indices result")]
-    CheckSignatureIndices,
+    CheckSignatureIndices(#[source] alloy_contract::Error),
+
+    /// Failed to check the signatures of a message
+    #[error("Failed to check signatures")]
+    CheckSignatures(#[source] alloy_contract::Error),
 
     /// Get Operator Id
     #[error("Get Operator Id")]
-    GetOperatorId,
+    GetOperatorId(#[source] alloy_contract::Error),
 
     /// Get Operator from Id
     #[error("Get Operator from Id")]
-    GetOperatorFromId,
+    GetOperatorFromId(#[source] alloy_contract::Error),
 
     /// Get Operator Status
     #[error("Get Operator Status")]
-    GetOperatorStatus,
+    GetOperatorStatus(#[source] alloy_contract::Error),
 
     /// Eth logs query
     #[error("Failed to get eth_logs")]
-    GetEthLogs,
+    GetEthLogs(#[source] TransportError),
 
     /// Failed to decode event
     #[error("Failed to decode New PubKey Registration Filter")]
@@ -75,49 +90,163 @@ pub enum AvsRegistryError {
     #[error("failed to decode  operator socket update filter ")]
     DecodeEventOperatorSocketUpdateFilter,
 
+    /// Failed to get the strategy params of a quorum
+    #[error("Failed to get strategy params")]
+    GetStrategyParams(#[source] alloy_contract::Error),
+
+    /// Failed to get the minimum stake of a quorum
+    #[error("Failed to get minimum stake for quorum")]
+    GetMinimumStake(#[source] alloy_contract::Error),
+
+    /// Failed to get the operator set params of a quorum
+    #[error("Failed to get operator set params")]
+    GetOperatorSetParams(#[source] alloy_contract::Error),
+
+    /// Failed to get the churn approver
+    #[error("Failed to get churn approver")]
+    GetChurnApprover(#[source] alloy_contract::Error),
+
     /// Failed to get Service Manager
     #[error("Failed to get service manager")]
-    GetServiceManager,
+    GetServiceManager(#[source] alloy_contract::Error),
 
     /// Failed to get stake registry
     #[error("Failed to get stake registry address")]
-    GetStakeRegistry,
+    GetStakeRegistry(#[source] alloy_contract::Error),
 
     /// Failed to get delegation manager address
     #[error("Failed to get delegation manager address")]
-    GetDelegation,
+    GetDelegation(#[source] alloy_contract::Error),
 
     /// Failed to get avs registry address
     #[error("failed to get avs registry ")]
-    GetAvsRegistry,
+    GetAvsRegistry(#[source] alloy_contract::Error),
 
     /// Pubey registration msg hash
     #[error("Failed to regiser pub key message hash")]
-    PubKeyRegistrationMessageHash,
+    PubKeyRegistrationMessageHash(#[source] alloy_contract::Error),
 
     /// Failed to calculate operator avs registration digest hash
     #[error(" Failed to calculate operator avs registration digest hash")]
-    CalculateOperatorAvsRegistrationDigestHash,
+    CalculateOperatorAvsRegistrationDigestHash(#[source] ElContractsError),
 
     /// Register Operator
     #[error("Failed to register operatror")]
-    RegisterOperator,
+    RegisterOperator(#[source] WalletError),
+
+    /// Failed to register operator with churn
+    #[error("Failed to register operator with churn")]
+    RegisterOperatorWithChurn(#[source] WalletError),
+
+    /// Failed to get the weight of an operator in a quorum
+    #[error("Failed to get operator weight for quorum")]
+    GetOperatorWeight(#[source] alloy_contract::Error),
+
+    /// Failed to calculate operator churn approval digest hash
+    #[error("Failed to calculate operator churn approval digest hash")]
+    CalculateOperatorChurnApprovalDigestHash(#[source] alloy_contract::Error),
+
+    /// Failed to sign the churn approval
+    #[error("Failed to sign churn approval")]
+    SignChurnApproval(#[source] alloy_signer::Error),
+
+    /// Failed to update the socket of the operator
+    #[error("Failed to update operator socket")]
+    UpdateSocket(#[source] WalletError),
+
+    /// Socket is malformed
+    #[error("invalid operator socket")]
+    InvalidSocket(#[source] SocketError),
+
+    /// Failed to create a quorum
+    #[error("Failed to create quorum")]
+    CreateQuorum(#[source] WalletError),
+
+    /// Failed to set the operator set params of a quorum
+    #[error("Failed to set operator set params")]
+    SetOperatorSetParams(#[source] WalletError),
+
+    /// Failed to set the churn approver
+    #[error("Failed to set churn approver")]
+    SetChurnApprover(#[source] WalletError),
+
+    /// Failed to set the ejector
+    #[error("Failed to set ejector")]
+    SetEjector(#[source] WalletError),
+
+    /// Failed to eject an operator
+    #[error("Failed to eject operator")]
+    EjectOperator(#[source] WalletError),
+
+    /// Failed to add strategies to a quorum
+    #[error("Failed to add strategies")]
+    AddStrategies(#[source] WalletError),
+
+    /// Failed to remove strategies from a quorum
+    #[error("Failed to remove strategies")]
+    RemoveStrategies(#[source] WalletError),
+
+    /// Failed to modify the strategy params of a quorum
+    #[error("Failed to modify strategy params")]
+    ModifyStrategyParams(#[source] WalletError),
+
+    /// Failed to set the minimum stake of a quorum
+    #[error("Failed to set minimum stake for quorum")]
+    SetMinimumStake(#[source] WalletError),
+
+    /// Failed to get the restakeable strategies of the service manager
+    #[error("Failed to get restakeable strategies")]
+    GetRestakeableStrategies(#[source] alloy_contract::Error),
+
+    /// Failed to get the restaked strategies of an operator
+    #[error("Failed to get operator restaked strategies")]
+    GetOperatorRestakedStrategies(#[source] alloy_contract::Error),
+
+    /// Failed to update the AVS metadata URI
+    #[error("Failed to update avs metadata uri")]
+    UpdateAvsMetadataUri(#[source] WalletError),
+
+    /// Failed to fetch the metadata document of a metadata URI
+    #[error("Failed to fetch metadata at {uri}")]
+    FetchMetadata {
+        /// Metadata URI
+        uri: String,
+        /// Error of the request
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// Metadata document is not valid AVS metadata
+    #[error("invalid avs metadata")]
+    InvalidMetadata(#[source] MetadataError),
+
+    /// Transaction was mined but reverted
+    #[error(transparent)]
+    TransactionReverted(#[from] Box<TxReverted>),
+
+    /// Failed to simulate a transaction
+    #[error("Failed to simulate transaction")]
+    Simulate(#[source] SimulationError),
+
+    /// Value does not fit in the uint96 of the stake registry
+    #[error("{0} overflows uint96")]
+    Uint96Overflow(U256),
 
     /// Update Operator for Quorum
     #[error("Failed to update operator for quorum")]
-    UpdateOperatorForQuorum,
+    UpdateOperatorForQuorum(#[source] WalletError),
 
     /// Failed to Deregister operator
     #[error("failed to deregister operator")]
-    DeregisterOperator,
+    DeregisterOperator(#[source] WalletError),
 
     /// build el chain reader
     #[error("Failed to build el chain reader")]
-    BuildElChainReader,
+    BuildElChainReader(#[source] ElContractsError),
 
     /// Update stake for all quorums
     #[error("Failed to update stake foor all quorums")]
-    UpdateStakeForAllQuorums,
+    UpdateStakeForAllQuorums(#[source] WalletError),
 
     /// Failed to get g2 projective
     #[error("pub key g2")]
@@ -125,5 +254,225 @@ pub enum AvsRegistryError {
 
     /// Failed to subscribe to logs
     #[error("Could not subscribe to logs ")]
-    SubscribeLogs,
+    SubscribeLogs(#[source] TransportError),
+
+    /// Wallet failed to get its address or sign
+    #[error("wallet error")]
+    Wallet(#[from] WalletError),
+
+    /// Provider is not connected to the expected chain or contracts
+    #[error(transparent)]
+    Chain(#[from] ChainError),
+
+    /// EIP-712 signer is not the account sending the transactions
+    #[error("EIP-712 signer {signer} is not the operator {operator} sending the transactions")]
+    SignerMismatch {
+        /// Address of the EIP-712 signer
+        signer: Address,
+        /// Address of the transaction sender
+        operator: Address,
+    },
+
+    /// Key signing the churn approval is not the churn approver of the registry coordinator
+    #[error("signer {signer} is not the churn approver {churn_approver}")]
+    ChurnApproverMismatch {
+        /// Address of the key signing the churn approval
+        signer: Address,
+        /// Churn approver of the registry coordinator
+        churn_approver: Address,
+    },
+
+    /// No operator of a full quorum can be churned out for the registering operator
+    #[error("no operator of quorum {quorum_number} can be churned out")]
+    ChurnNotPossible {
+        /// Quorum at its maximum operator count
+        quorum_number: u8,
+    },
+
+    /// Registering operator stake does not meet the churn thresholds of the quorum
+    #[error("insufficient stake for churn")]
+    InsufficientStakeForChurn,
+
+    /// Failed to hash the typed data
+    #[error("failed to hash typed data")]
+    TypedDataHash(#[source] alloy_dyn_abi::Error),
+
+    /// Signing hash of the typed data differs from the digest computed by the contract
+    #[error("typed data hash {typed_data_hash} differs from contract digest {digest}")]
+    TypedDataDigestMismatch {
+        /// EIP-712 signing hash of the typed data
+        typed_data_hash: FixedBytes<32>,
+        /// Digest computed by the contract
+        digest: FixedBytes<32>,
+    },
+
+    /// Operator is already registered in some of the quorums
+    #[error("operator is already registered")]
+    OperatorAlreadyRegistered,
+
+    /// Operator is not registered in the quorums
+    #[error("operator is not registered")]
+    OperatorNotRegistered,
+
+    /// Operator is not registered in EigenLayer
+    #[error("operator is not registered in EigenLayer")]
+    OperatorNotRegisteredInEigenLayer,
+
+    /// Quorum does not exist
+    #[error("quorum does not exist")]
+    QuorumDoesNotExist,
+
+    /// Operator stake is below the minimum stake of the quorum
+    #[error("operator does not meet the minimum stake of the quorum")]
+    InsufficientStake,
+
+    /// Quorum reached its maximum operator count, registration requires churning
+    #[error("quorum operator count exceeds maximum")]
+    MaxOperatorCountReached,
+
+    /// Signature is past its expiry
+    #[error("signature expired")]
+    SignatureExpired,
+
+    /// Salt of the signature has already been used
+    #[error("signature salt already spent")]
+    SaltAlreadySpent,
+
+    /// Pubkey registration signature is invalid
+    #[error("invalid pubkey registration signature")]
+    InvalidPubkeyRegistrationSignature,
+
+    /// Aggregated signature does not verify against the signers apk
+    #[error("invalid aggregated signature")]
+    InvalidSignature,
+
+    /// Sender is not the owner of the registry coordinator
+    #[error("caller is not the owner")]
+    NotOwner,
+
+    /// Sender is not the ejector of the registry coordinator
+    #[error("caller is not the ejector")]
+    NotEjector,
+
+    /// Called functionality is paused
+    #[error("functionality is paused")]
+    Paused,
+
+    /// Contract reverted with a reason not mapped to a variant
+    #[error("contract reverted: {0}")]
+    Reverted(String),
+
+    /// Contract reverted with a custom error, given as its selector and arguments, or
+    /// without data
+    #[error("contract reverted with custom error {0}")]
+    CustomError(Bytes),
+}
+
+impl AvsRegistryError {
+    /// Variant of a contract revert with `reason`
+    pub fn from_revert_reason(reason: String) -> Self {
+        let (_, message) = reason.split_once(": ").unwrap_or(("", &reason));
+        match message {
+            "operator already registered for some quorums being registered for" => {
+                Self::OperatorAlreadyRegistered
+            }
+            "operator is not registered" | "operator is not registered for specified quorums" => {
+                Self::OperatorNotRegistered
+            }
+            "operator not registered to EigenLayer yet" => Self::OperatorNotRegisteredInEigenLayer,
+            "quorum does not exist" => Self::QuorumDoesNotExist,
+            "Operator does not meet minimum stake requirement for quorum" => {
+                Self::InsufficientStake
+            }
+            "operator count exceeds maximum" => Self::MaxOperatorCountReached,
+            "operator signature expired" | "churnApprover signature expired" => {
+                Self::SignatureExpired
+            }
+            "salt already spent" | "churnApprover salt already used" => Self::SaltAlreadySpent,
+            "incoming operator has insufficient stake for churn"
+            | "cannot kick operator with more than kickBIPsOfTotalStake" => {
+                Self::InsufficientStakeForChurn
+            }
+            "either the G1 signature is wrong, or G2 private key does not match G1 private key" => {
+                Self::InvalidPubkeyRegistrationSignature
+            }
+            "signature is invalid" => Self::InvalidSignature,
+            "caller is not the owner" | "caller is not the owner of the registryCoordinator" => {
+                Self::NotOwner
+            }
+            "caller is not the ejector" => Self::NotEjector,
+            "index is paused" => Self::Paused,
+            _ => Self::Reverted(reason),
+        }
+    }
+
+    /// Variant of the revert of a simulated transaction
+    pub fn from_simulated_revert(revert: SimulatedRevert) -> Self {
+        match revert {
+            SimulatedRevert::Reason(reason) => Self::from_revert_reason(reason),
+            SimulatedRevert::Data(data) => Self::CustomError(data),
+        }
+    }
+
+    /// Custom error of a [`Self::CustomError`], decoded against `E`, the errors of the
+    /// reverting contract
+    pub fn decode_custom_error<E: SolInterface>(&self) -> Option<E> {
+        match self {
+            Self::CustomError(data) => E::abi_decode(data, true).ok(),
+            _ => None,
+        }
+    }
+
+    /// Error of a failed call: the variant of its revert reason if it reverted, else
+    /// `error` wrapping `source`.
+    pub(crate) fn from_call<E>(source: E, error: impl FnOnce(E) -> Self) -> Self
+    where
+        E: std::error::Error + 'static,
+    {
+        match revert_reason(&source) {
+            Some(reason) => Self::from_revert_reason(reason),
+            None => error(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_revert_reason() {
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason(
+                "RegistryCoordinator._registerOperator: operator already registered for some quorums being registered for".into()
+            ),
+            AvsRegistryError::OperatorAlreadyRegistered
+        ));
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason(
+                "AVSDirectory.registerOperatorToAVS: salt already spent".into()
+            ),
+            AvsRegistryError::SaltAlreadySpent
+        ));
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason(
+                "RegistryCoordinator._validateChurn: incoming operator has insufficient stake for churn".into()
+            ),
+            AvsRegistryError::InsufficientStakeForChurn
+        ));
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason(
+                "BLSSignatureChecker.checkSignatures: signature is invalid".into()
+            ),
+            AvsRegistryError::InvalidSignature
+        ));
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason("Ownable: caller is not the owner".into()),
+            AvsRegistryError::NotOwner
+        ));
+        assert!(matches!(
+            AvsRegistryError::from_revert_reason("panic: arithmetic underflow or overflow (0x11)".into()),
+            AvsRegistryError::Reverted(reason) if reason.starts_with("panic")
+        ));
+    }
 }
@@ -0,0 +1,122 @@
+//! Decoding of the operator registration and stake update event logs.
+use alloy_primitives::U256;
+use alloy_rpc_types::Log;
+use alloy_sol_types::SolEvent;
+use eigen_types::operator::{
+    OperatorRegistrationEvent, OperatorStakeUpdateEvent, RegistrationKind,
+};
+use eigen_utils::binding::{RegistryCoordinator, StakeRegistry};
+
+/// Signatures of the registration events of the RegistryCoordinator
+pub(crate) const OPERATOR_REGISTRATION_EVENTS: [&str; 2] = [
+    "OperatorRegistered(address,bytes32)",
+    "OperatorDeregistered(address,bytes32)",
+];
+
+/// Signature of the stake update event of the StakeRegistry
+pub(crate) const OPERATOR_STAKE_UPDATE_EVENT: &str = "OperatorStakeUpdate(bytes32,uint8,uint96)";
+
+/// Registration event of `log`, `None` if it is another event or a pending log
+pub(crate) fn operator_registration_event(log: &Log) -> Option<OperatorRegistrationEvent> {
+    // decoding does not check the signature, both events having the same parameters
+    let (kind, operator, operator_id) = match *log.topics().first()? {
+        RegistryCoordinator::OperatorRegistered::SIGNATURE_HASH => {
+            let event = log
+                .log_decode::<RegistryCoordinator::OperatorRegistered>()
+                .ok()?;
+            let data = event.data();
+            (RegistrationKind::Registered, data.operator, data.operatorId)
+        }
+        RegistryCoordinator::OperatorDeregistered::SIGNATURE_HASH => {
+            let event = log
+                .log_decode::<RegistryCoordinator::OperatorDeregistered>()
+                .ok()?;
+            let data = event.data();
+            (
+                RegistrationKind::Deregistered,
+                data.operator,
+                data.operatorId,
+            )
+        }
+        _ => return None,
+    };
+    Some(OperatorRegistrationEvent {
+        kind,
+        operator,
+        operator_id,
+        block_number: log.block_number?,
+        transaction_hash: log.transaction_hash?,
+    })
+}
+
+/// Stake update event of `log`, `None` if it is another event or a pending log
+pub(crate) fn operator_stake_update_event(log: &Log) -> Option<OperatorStakeUpdateEvent> {
+    if *log.topics().first()? != StakeRegistry::OperatorStakeUpdate::SIGNATURE_HASH {
+        return None;
+    }
+    let event = log
+        .log_decode::<StakeRegistry::OperatorStakeUpdate>()
+        .ok()?;
+    let data = event.data();
+    Some(OperatorStakeUpdateEvent {
+        operator_id: data.operatorId,
+        quorum: data.quorumNumber,
+        stake: U256::from(data.stake),
+        block_number: log.block_number?,
+        transaction_hash: log.transaction_hash?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address, FixedBytes, LogData, TxHash};
+
+    fn log(event: &impl SolEvent, block_number: Option<u64>) -> Log {
+        let topics = event.encode_topics().into_iter().map(|t| t.0).collect();
+        Log {
+            inner: alloy_primitives::Log {
+                address: Address::ZERO,
+                data: LogData::new_unchecked(topics, event.encode_data().into()),
+            },
+            block_number,
+            transaction_hash: Some(TxHash::repeat_byte(7)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_operator_registration_event() {
+        let operator = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+        let operator_id = FixedBytes::repeat_byte(1);
+        let deregistered = RegistryCoordinator::OperatorDeregistered {
+            operator,
+            operatorId: operator_id,
+        };
+
+        let event = operator_registration_event(&log(&deregistered, Some(12))).unwrap();
+        assert_eq!(
+            event,
+            OperatorRegistrationEvent {
+                kind: RegistrationKind::Deregistered,
+                operator,
+                operator_id,
+                block_number: 12,
+                transaction_hash: TxHash::repeat_byte(7),
+            }
+        );
+        assert!(operator_registration_event(&log(&deregistered, None)).is_none());
+
+        let stake_update = StakeRegistry::OperatorStakeUpdate {
+            operatorId: operator_id,
+            quorumNumber: 1,
+            stake: 500,
+        };
+        let log = log(&stake_update, Some(12));
+        assert!(operator_registration_event(&log).is_none());
+        assert_eq!(
+            operator_stake_update_event(&log).unwrap().stake,
+            U256::from(500)
+        );
+    }
+}
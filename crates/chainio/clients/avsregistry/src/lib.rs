@@ -17,5 +17,26 @@ pub mod subscriber;
 /// Writer module
 pub mod writer;
 
+/// Admin writer module
+pub mod admin;
+
+/// Service manager module
+pub mod service_manager;
+
 /// Avs registry error message
 pub mod error;
+
+mod events;
+
+/// Held by the tests sending transactions from the owner of the anvil deployment, for
+/// their nonces not to race
+#[cfg(test)]
+static ANVIL_OWNER_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Http endpoint of the anvil node the tests run against
+#[cfg(test)]
+const ANVIL_HTTP_URL: &str = "http://localhost:8545";
+
+/// Key of the owner, churn approver and ejector of the anvil deployment
+#[cfg(test)]
+const OWNER_PRIVATE_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
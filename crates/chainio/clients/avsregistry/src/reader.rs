@@ -1,18 +1,39 @@
 use crate::error::AvsRegistryError;
+use crate::events::{
+    operator_registration_event, operator_stake_update_event, OPERATOR_REGISTRATION_EVENTS,
+    OPERATOR_STAKE_UPDATE_EVENT,
+};
 use alloy_primitives::{Address, Bytes, FixedBytes, B256, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::Filter;
-use ark_ff::Zero;
-use eigen_types::operator::{bitmap_to_quorum_ids, OperatorPubKeys};
+use async_trait::async_trait;
+use eigen_client_eth::chain::verify_chain_and_contracts;
+use eigen_types::{
+    avs::QuorumStakeTotals,
+    operator::{
+        bitmap_to_quorum_ids, OperatorPubKeys, OperatorRegistrationEvent, OperatorStake,
+        OperatorStakeUpdateEvent, QuorumOperatorSet, StakeUpdate,
+    },
+    quorum::{QuorumConfig, StrategyParams},
+};
 use eigen_utils::{
-    binding::{BLSApkRegistry, OperatorStateRetriever, RegistryCoordinator, StakeRegistry},
+    binding::{
+        BLSApkRegistry,
+        IBLSSignatureChecker::{self, NonSignerStakesAndSignature},
+        OperatorStateRetriever, RegistryCoordinator, StakeRegistry,
+    },
     get_provider,
+    log_scanner::LogScanner,
 };
-use num_bigint::BigInt;
+use futures_util::{stream, StreamExt, TryStreamExt};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use tracing::debug;
 
+/// Number of total stake updates requested concurrently by
+/// [`AvsRegistryReader::get_total_stake_history`]
+const STAKE_HISTORY_CONCURRENCY: usize = 8;
+
 /// Avs Registry chainreader
 #[derive(Debug, Clone, Default)]
 pub struct AvsRegistryChainReader {
@@ -21,204 +42,461 @@ pub struct AvsRegistryChainReader {
     operator_state_retriever: Address,
     stake_registry_addr: Address,
     provider: String,
+    log_scanner: LogScanner,
 }
 
-trait AvsRegistryReader {
-    fn get_quorum_count() -> Result<u8, String>;
+/// Read access to the AVS registry contracts.
+///
+/// Implemented by [`AvsRegistryChainReader`]; alternative backends (mocks, caches,
+/// indexers) implement it to be used in its place.
+#[async_trait]
+pub trait AvsRegistryReader: Debug + Send + Sync {
+    /// Get quorum count
+    async fn get_quorum_count(&self) -> Result<u8, AvsRegistryError>;
+
+    /// Get the strategies, minimum stake and operator set params of `quorum_number`,
+    /// failing with [`AvsRegistryError::QuorumDoesNotExist`] if it is not created yet
+    async fn get_quorum_config(&self, quorum_number: u8) -> Result<QuorumConfig, AvsRegistryError>;
+
+    /// Get the config of every quorum, in quorum number order
+    async fn get_quorum_configs(&self) -> Result<Vec<QuorumConfig>, AvsRegistryError>;
+
+    /// Get operators stake in quorums at a particular block
+    async fn get_operators_stake_in_quorums_at_block(
+        &self,
+        block_number: u32,
+        quorum_numbers: Bytes,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+    /// Get operators stake in the quorums of `operator_id` at a particular block, with its quorum bitmap
+    async fn get_operators_stake_in_quorums_at_block_operator_id(
+        &self,
+        block_number: u32,
+        operator_id: B256,
+    ) -> Result<(U256, QuorumOperatorSet), AvsRegistryError>;
+
+    /// Get operators stake in quorums at the current block
+    async fn get_operators_stake_in_quorums_at_current_block(
+        &self,
+        quorum_numbers: Bytes,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+    /// Get operators stake in the quorums of `operator_id` at a particular block
+    async fn get_operators_stake_in_quorums_of_operator_at_block(
+        &self,
+        operator_id: B256,
+        block_number: u32,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+    /// Get operators stake in the quorums of `operator_id` at the current block
+    async fn get_operators_stake_in_quorums_of_operator_at_current_block(
+        &self,
+        operator_id: B256,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+    /// Get the stake of `operator_id` in each of its quorums at the current block, read
+    /// from the stake registry
+    async fn get_operator_stake_in_quorums_of_operator_at_current_block(
+        &self,
+        operator_id: B256,
+    ) -> Result<Vec<OperatorStake>, AvsRegistryError>;
+
+    /// Get the stake updates of `operator_id` in `quorum_number`, oldest first
+    async fn get_stake_history(
+        &self,
+        operator_id: B256,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError>;
+
+    /// Get the total stake updates of `quorum_number`, oldest first
+    async fn get_total_stake_history(
+        &self,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError>;
+
+    /// Get the stake of `operator_id` in `quorum_number` at `block_number` from its stake
+    /// update at `index`, which must be the update active at that block
+    async fn get_stake_at_block_number_and_index(
+        &self,
+        quorum_number: u8,
+        block_number: u32,
+        operator_id: B256,
+        index: U256,
+    ) -> Result<U256, AvsRegistryError>;
+
+    /// Get Signature indices
+    async fn get_check_signatures_indices(
+        &self,
+        reference_block_number: u32,
+        quorum_numbers: Vec<u8>,
+        non_signer_operator_ids: Vec<FixedBytes<32>>,
+    ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError>;
+
+    /// Get Operator Id
+    async fn get_operator_id(
+        &self,
+        operator_address: Address,
+    ) -> Result<FixedBytes<32>, AvsRegistryError>;
+
+    /// Get Operator from operator id
+    async fn get_operator_from_id(
+        &self,
+        operator_id: [u8; 32],
+    ) -> Result<Address, AvsRegistryError>;
+
+    /// Check if operator is registered
+    async fn is_operator_registered(
+        &self,
+        operator_address: Address,
+    ) -> Result<bool, AvsRegistryError>;
+
+    /// Query the pubkeys of the operators registered between `start_block` and `stop_block`
+    ///
+    /// A `stop_block` of 0 queries up to the current block.
+    async fn query_existing_registered_operator_pub_keys(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<(Vec<Address>, Vec<OperatorPubKeys>), AvsRegistryError>;
+
+    /// Query the sockets of the operators registered between `start_block` and `stop_block`
+    ///
+    /// A `stop_block` of 0 queries up to the current block.
+    async fn query_existing_registered_operator_sockets(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<HashMap<FixedBytes<32>, String>, AvsRegistryError>;
+
+    /// Query the operator registrations and deregistrations between `start_block` and
+    /// `stop_block`, in block order
+    ///
+    /// A `stop_block` of 0 queries up to the current block.
+    async fn query_operator_registration_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorRegistrationEvent>, AvsRegistryError>;
+
+    /// Query the operator stake updates between `start_block` and `stop_block`, in block
+    /// order
+    ///
+    /// A `stop_block` of 0 queries up to the current block.
+    async fn query_operator_stake_update_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorStakeUpdateEvent>, AvsRegistryError>;
 }
 
 impl AvsRegistryChainReader {
     /// New AvsRegistryChainReader instance
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. Every
+    /// given and resolved contract address is checked to have code deployed.
     pub async fn new(
         registry_coordinator_addr: Address,
         operator_state_retriever_addr: Address,
         provider_url: String,
-    ) -> Result<AvsRegistryChainReader, Box<dyn std::error::Error>> {
+        expected_chain_id: Option<u64>,
+    ) -> Result<AvsRegistryChainReader, AvsRegistryError> {
+        verify_chain_and_contracts(
+            &provider_url,
+            expected_chain_id,
+            &[registry_coordinator_addr, operator_state_retriever_addr],
+        )
+        .await?;
+
         let provider = get_provider(&provider_url);
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(registry_coordinator_addr, &provider);
 
-        let bls_apk_registry_addr_result =
-            contract_registry_coordinator.blsApkRegistry().call().await;
-
-        match bls_apk_registry_addr_result {
-            Ok(bls_apk_registry_return) => {
-                let RegistryCoordinator::blsApkRegistryReturn {
-                    _0: bls_apk_registry_addr,
-                } = bls_apk_registry_return;
-
-                let stake_registry_addr_result =
-                    contract_registry_coordinator.stakeRegistry().call().await;
-
-                match stake_registry_addr_result {
-                    Ok(stake_registry_return) => {
-                        let RegistryCoordinator::stakeRegistryReturn {
-                            _0: stake_registry_addr,
-                        } = stake_registry_return;
-
-                        Ok(AvsRegistryChainReader {
-                            bls_apk_registry_addr,
-                            registry_coordinator_addr,
-                            operator_state_retriever: operator_state_retriever_addr,
-                            stake_registry_addr,
-                            provider: provider_url.clone(),
-                        })
-                    }
-                    Err(_) => Err(Box::new(AvsRegistryError::GetStakeRegistry)),
-                }
-            }
+        let RegistryCoordinator::blsApkRegistryReturn {
+            _0: bls_apk_registry_addr,
+        } = contract_registry_coordinator
+            .blsApkRegistry()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetBlsApkRegistry))?;
+
+        let RegistryCoordinator::stakeRegistryReturn {
+            _0: stake_registry_addr,
+        } = contract_registry_coordinator
+            .stakeRegistry()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStakeRegistry))?;
+
+        verify_chain_and_contracts(
+            &provider_url,
+            None,
+            &[bls_apk_registry_addr, stake_registry_addr],
+        )
+        .await?;
+
+        Ok(AvsRegistryChainReader {
+            bls_apk_registry_addr,
+            registry_coordinator_addr,
+            operator_state_retriever: operator_state_retriever_addr,
+            stake_registry_addr,
+            provider: provider_url.clone(),
+            log_scanner: LogScanner::default(),
+        })
+    }
+
+    /// Use `log_scanner` for the event queries, e.g. to set its concurrency or to report
+    /// its progress
+    pub fn with_log_scanner(mut self, log_scanner: LogScanner) -> Self {
+        self.log_scanner = log_scanner;
+        self
+    }
+
+    /// Config of `quorum_number`, which must be below the quorum count. The contracts
+    /// return zeroed params for a quorum that does not exist.
+    async fn read_quorum_config(
+        &self,
+        quorum_number: u8,
+    ) -> Result<QuorumConfig, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
 
-            Err(_) => Err(Box::new(AvsRegistryError::GetBlsApkRegistry)),
+        let stake_registry = StakeRegistry::new(self.stake_registry_addr, &provider);
+        let StakeRegistry::strategyParamsLengthReturn { _0: strategies_len } = stake_registry
+            .strategyParamsLength(quorum_number)
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStrategyParams))?;
+
+        let mut strategies = Vec::new();
+        for index in 0..strategies_len.saturating_to::<u64>() {
+            let StakeRegistry::strategyParamsByIndexReturn { _0: params } = stake_registry
+                .strategyParamsByIndex(quorum_number, U256::from(index))
+                .call()
+                .await
+                .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStrategyParams))?;
+            strategies.push(StrategyParams {
+                strategy: params.strategy,
+                multiplier: U256::from(params.multiplier),
+            });
         }
+
+        let StakeRegistry::minimumStakeForQuorumReturn { _0: minimum_stake } = stake_registry
+            .minimumStakeForQuorum(quorum_number)
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetMinimumStake))?;
+
+        let registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
+        let RegistryCoordinator::getOperatorSetParamsReturn { _0: params } = registry_coordinator
+            .getOperatorSetParams(quorum_number)
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorSetParams))?;
+
+        let RegistryCoordinator::churnApproverReturn { _0: churn_approver } = registry_coordinator
+            .churnApprover()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetChurnApprover))?;
+
+        Ok(QuorumConfig {
+            quorum_number,
+            strategies,
+            minimum_stake: U256::from(minimum_stake),
+            operator_set_params: params.into(),
+            churn_approver,
+        })
     }
 
-    /// Get quorum count
-    pub async fn get_quorum_count(&self) -> Result<u8, Box<dyn std::error::Error>> {
+    /// `stop_block`, or the current block if it is 0
+    async fn resolve_stop_block(&self, stop_block: u64) -> Result<u64, AvsRegistryError> {
+        if stop_block != 0 {
+            return Ok(stop_block);
+        }
+        get_provider(&self.provider)
+            .get_block_number()
+            .await
+            .map_err(AvsRegistryError::GetBlockNumber)
+    }
+
+    /// Dry run of `checkSignatures` of the BLSSignatureChecker at
+    /// `bls_signature_checker_addr`, e.g. the task manager of the AVS, with an `eth_call`.
+    ///
+    /// Returns the signed and total stake of each quorum of `quorum_numbers`, or the
+    /// error of the decoded revert reason if the signatures would not be accepted.
+    pub async fn check_signatures(
+        &self,
+        bls_signature_checker_addr: Address,
+        msg_hash: B256,
+        quorum_numbers: Bytes,
+        reference_block_number: u32,
+        non_signer_stakes_and_signature: NonSignerStakesAndSignature,
+    ) -> Result<QuorumStakeTotals, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+
+        let contract_bls_signature_checker =
+            IBLSSignatureChecker::new(bls_signature_checker_addr, provider);
+        let IBLSSignatureChecker::checkSignaturesReturn {
+            _0: stake_totals,
+            _1: signatory_record_hash,
+        } = contract_bls_signature_checker
+            .checkSignatures(
+                msg_hash,
+                quorum_numbers.clone(),
+                reference_block_number,
+                non_signer_stakes_and_signature,
+            )
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::CheckSignatures))?;
+
+        Ok(QuorumStakeTotals {
+            quorum_numbers: quorum_numbers.to_vec(),
+            signed_stake_for_quorum: stake_totals
+                .signedStakeForQuorum
+                .into_iter()
+                .map(U256::from)
+                .collect(),
+            total_stake_for_quorum: stake_totals
+                .totalStakeForQuorum
+                .into_iter()
+                .map(U256::from)
+                .collect(),
+            signatory_record_hash,
+        })
+    }
+}
+
+#[async_trait]
+impl AvsRegistryReader for AvsRegistryChainReader {
+    async fn get_quorum_count(&self) -> Result<u8, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
-        let quorum_count_result = contract_registry_coordinator.quorumCount().call().await;
+        let RegistryCoordinator::quorumCountReturn { _0: quorum } = contract_registry_coordinator
+            .quorumCount()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetQuorumCount))?;
+        Ok(quorum)
+    }
 
-        match quorum_count_result {
-            Ok(quorum_count) => {
-                let RegistryCoordinator::quorumCountReturn { _0: quorum } = quorum_count;
-                Ok(quorum)
-            }
+    async fn get_quorum_config(&self, quorum_number: u8) -> Result<QuorumConfig, AvsRegistryError> {
+        if quorum_number >= self.get_quorum_count().await? {
+            return Err(AvsRegistryError::QuorumDoesNotExist);
+        }
+        self.read_quorum_config(quorum_number).await
+    }
 
-            Err(_) => Err(Box::new(AvsRegistryError::GetQuorumCount)),
+    async fn get_quorum_configs(&self) -> Result<Vec<QuorumConfig>, AvsRegistryError> {
+        let quorum_count = self.get_quorum_count().await?;
+        let mut configs = Vec::with_capacity(quorum_count.into());
+        for quorum_number in 0..quorum_count {
+            configs.push(self.read_quorum_config(quorum_number).await?);
         }
+        Ok(configs)
     }
 
-    /// Get operators stake in quorums at a particular block
-    pub async fn get_operators_stake_in_quorums_at_block(
+    async fn get_operators_stake_in_quorums_at_block(
         &self,
         block_number: u32,
         quorum_numbers: Bytes,
-    ) -> Result<Vec<Vec<OperatorStateRetriever::Operator>>, Box<dyn std::error::Error>> {
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_operator_state_retriever =
             OperatorStateRetriever::new(self.operator_state_retriever, provider);
-        let operator_state_result = contract_operator_state_retriever
-            .getOperatorState_0(self.registry_coordinator_addr, quorum_numbers, block_number)
-            .call()
-            .await;
-
-        match operator_state_result {
-            Ok(operator_state) => {
-                let OperatorStateRetriever::getOperatorState_0Return { _0: quorum } =
-                    operator_state;
-                Ok(quorum)
-            }
-            Err(_) => Err(Box::new(AvsRegistryError::GetOperatorState)),
-        }
+        let OperatorStateRetriever::getOperatorState_0Return { _0: operator_state } =
+            contract_operator_state_retriever
+                .getOperatorState_0(
+                    self.registry_coordinator_addr,
+                    quorum_numbers.clone(),
+                    block_number,
+                )
+                .call()
+                .await
+                .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorState))?;
+        Ok(QuorumOperatorSet::from_operator_state(
+            &quorum_numbers,
+            operator_state,
+        ))
     }
 
-    /// Get operators stake in quorums at block operator id
-    pub async fn get_operators_stake_in_quorums_at_block_operator_id(
+    async fn get_operators_stake_in_quorums_at_block_operator_id(
         &self,
         block_number: u32,
         operator_id: B256,
-    ) -> Result<(U256, Vec<Vec<OperatorStateRetriever::Operator>>), Box<dyn std::error::Error>>
-    {
+    ) -> Result<(U256, QuorumOperatorSet), AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_operator_state_retriever =
             OperatorStateRetriever::new(self.operator_state_retriever, provider);
-        let operator_state_with_registry_coordinator_and_oeprator_id_result =
-            contract_operator_state_retriever
-                .getOperatorState_1(self.registry_coordinator_addr, operator_id, block_number)
-                .call()
-                .await;
-
-        match operator_state_with_registry_coordinator_and_oeprator_id_result {
-            Ok(operator_state_with_registry_coordinator_and_oeprator_id) => {
-                let OperatorStateRetriever::getOperatorState_1Return {
-                    _0: stake,
-                    _1: operator_state,
-                } = operator_state_with_registry_coordinator_and_oeprator_id;
-                Ok((stake, operator_state))
-            }
-            Err(_) => Err(Box::new(
-                AvsRegistryError::GetOperatorStateWithRegistryCoordinatorAndOperatorId,
-            )),
-        }
+        let OperatorStateRetriever::getOperatorState_1Return {
+            _0: quorum_bitmap,
+            _1: operator_state,
+        } = contract_operator_state_retriever
+            .getOperatorState_1(self.registry_coordinator_addr, operator_id, block_number)
+            .call()
+            .await
+            .map_err(|e| {
+                AvsRegistryError::from_call(
+                    e,
+                    AvsRegistryError::GetOperatorStateWithRegistryCoordinatorAndOperatorId,
+                )
+            })?;
+        let quorums = bitmap_to_quorum_ids(quorum_bitmap);
+        Ok((
+            quorum_bitmap,
+            QuorumOperatorSet::from_operator_state(&quorums, operator_state),
+        ))
     }
 
-    /// Get operators stake in quorums at current block
-    pub async fn get_operators_stake_in_quorums_at_current_block(
+    async fn get_operators_stake_in_quorums_at_current_block(
         &self,
         quorum_numbers: Bytes,
-    ) -> Result<Vec<Vec<OperatorStateRetriever::Operator>>, Box<dyn std::error::Error>> {
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
-        let current_block_number_result = provider.get_block_number().await;
-
-        match current_block_number_result {
-            Ok(current_block_number) => {
-                if current_block_number > u32::MAX.into() {
-                    return Err(Box::new(AvsRegistryError::BlockNumberOverflow));
-                }
-
-                let operators_stake_in_quorums_at_block_result = self
-                    .get_operators_stake_in_quorums_at_block(
-                        current_block_number as u32,
-                        quorum_numbers,
-                    )
-                    .await;
-
-                match operators_stake_in_quorums_at_block_result {
-                    Ok(operators_stake_in_quorums_at_block) => {
-                        Ok(operators_stake_in_quorums_at_block)
-                    }
-                    Err(_) => Err(Box::new(
-                        AvsRegistryError::GetOperatorStakeInQuorumAtBlockNumber,
-                    )),
-                }
-            }
-            Err(_) => Err(Box::new(AvsRegistryError::GetBlockNumber)),
+        let current_block_number = provider
+            .get_block_number()
+            .await
+            .map_err(AvsRegistryError::GetBlockNumber)?;
+        if current_block_number > u32::MAX.into() {
+            return Err(AvsRegistryError::BlockNumberOverflow);
         }
+
+        self.get_operators_stake_in_quorums_at_block(current_block_number as u32, quorum_numbers)
+            .await
     }
 
-    /// Get operators stake in quorums of operator at block
-    pub async fn get_operators_stake_in_quorums_of_operator_at_block(
+    async fn get_operators_stake_in_quorums_of_operator_at_block(
         &self,
         operator_id: B256,
         block_number: u32,
-    ) -> Result<(Vec<u8>, Vec<Vec<OperatorStateRetriever::Operator>>), Box<dyn (std::error::Error)>>
-    {
-        let result_ = self
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        let (_, operator_stakes) = self
             .get_operators_stake_in_quorums_at_block_operator_id(block_number, operator_id)
-            .await;
-
-        match result_ {
-            Ok((quorum_bitmaps, operator_stakes)) => {
-                let quorums = bitmap_to_quorum_ids(quorum_bitmaps);
+            .await?;
 
-                let s = (quorums, operator_stakes);
-                Ok(s)
-            }
-            Err(_) => Err(Box::new(
-                AvsRegistryError::GetOperatorStakeInQuorumAtBlockOperatorId,
-            )),
-        }
+        Ok(operator_stakes)
     }
 
-    /// Get operators stake in quorums of operator at current block
-    pub async fn get_operators_stake_in_quorums_of_operator_at_current_block(
+    async fn get_operators_stake_in_quorums_of_operator_at_current_block(
         &self,
         operator_id: B256,
-    ) -> Result<(Vec<u8>, Vec<Vec<OperatorStateRetriever::Operator>>), Box<dyn std::error::Error>>
-    {
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
-        let current_block_number = provider.get_block_number().await?;
+        let current_block_number = provider
+            .get_block_number()
+            .await
+            .map_err(AvsRegistryError::GetBlockNumber)?;
 
         if current_block_number > u32::MAX.into() {
-            return Err(Box::new(AvsRegistryError::BlockNumberOverflow));
+            return Err(AvsRegistryError::BlockNumberOverflow);
         }
 
         let operator_stake_in_quorum_of_operaotr_at_block = self
@@ -230,11 +508,10 @@ impl AvsRegistryChainReader {
         Ok(operator_stake_in_quorum_of_operaotr_at_block)
     }
 
-    /// Get operator stake in quorums of operator at current block
-    pub async fn get_operator_stake_in_quorums_of_operator_at_current_block(
+    async fn get_operator_stake_in_quorums_of_operator_at_current_block(
         &self,
         operator_id: B256,
-    ) -> Result<HashMap<u8, BigInt>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<OperatorStake>, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let registry_coordinator =
@@ -243,34 +520,110 @@ impl AvsRegistryChainReader {
         let quorum_bitmap = registry_coordinator
             .getCurrentQuorumBitmap(operator_id)
             .call()
-            .await?;
+            .await
+            .map_err(|e| {
+                AvsRegistryError::from_call(e, AvsRegistryError::GetCurrentQuorumBitmap)
+            })?;
 
         let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quo } = quorum_bitmap;
 
         let quorums = bitmap_to_quorum_ids(quo);
+        let operator = self.get_operator_from_id(*operator_id).await?;
 
-        let mut quorum_stakes: HashMap<u8, BigInt> = HashMap::new();
+        let mut quorum_stakes = Vec::with_capacity(quorums.len());
 
         let stake_registry = StakeRegistry::new(self.stake_registry_addr, &provider);
         for quorum in quorums.iter() {
             let stakes_result = stake_registry
                 .getCurrentStake(operator_id, *quorum)
                 .call()
-                .await?;
+                .await
+                .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetCurrentStake))?;
 
             let StakeRegistry::getCurrentStakeReturn { _0: c_stake } = stakes_result;
-            quorum_stakes.insert(*quorum, c_stake.into());
+            quorum_stakes.push(OperatorStake {
+                operator,
+                operator_id,
+                quorum: *quorum,
+                stake: U256::from(c_stake),
+            });
         }
         Ok(quorum_stakes)
     }
 
-    /// Get Signature indices
-    pub async fn get_check_signatures_indices(
+    async fn get_stake_history(
+        &self,
+        operator_id: B256,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+
+        let stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let StakeRegistry::getStakeHistoryReturn { _0: history } = stake_registry
+            .getStakeHistory(operator_id, quorum_number)
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStakeHistory))?;
+        Ok(history.into_iter().map(stake_update).collect())
+    }
+
+    async fn get_total_stake_history(
+        &self,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+
+        let stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let StakeRegistry::getTotalStakeHistoryLengthReturn { _0: length } = stake_registry
+            .getTotalStakeHistoryLength(quorum_number)
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetTotalStakeHistory))?;
+
+        // The contract only exposes the total stake history one update at a time
+        let stake_registry = &stake_registry;
+        stream::iter(0..length.saturating_to::<u64>())
+            .map(|index| async move {
+                stake_registry
+                    .getTotalStakeUpdateAtIndex(quorum_number, U256::from(index))
+                    .call()
+                    .await
+                    .map(|update| stake_update(update._0))
+                    .map_err(|e| {
+                        AvsRegistryError::from_call(e, AvsRegistryError::GetTotalStakeHistory)
+                    })
+            })
+            .buffered(STAKE_HISTORY_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    async fn get_stake_at_block_number_and_index(
+        &self,
+        quorum_number: u8,
+        block_number: u32,
+        operator_id: B256,
+        index: U256,
+    ) -> Result<U256, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+
+        let stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let StakeRegistry::getStakeAtBlockNumberAndIndexReturn { _0: stake } = stake_registry
+            .getStakeAtBlockNumberAndIndex(quorum_number, block_number, operator_id, index)
+            .call()
+            .await
+            .map_err(|e| {
+                AvsRegistryError::from_call(e, AvsRegistryError::GetStakeAtBlockNumberAndIndex)
+            })?;
+        Ok(U256::from(stake))
+    }
+
+    async fn get_check_signatures_indices(
         &self,
         reference_block_number: u32,
         quorum_numbers: Vec<u8>,
         non_signer_operator_ids: Vec<FixedBytes<32>>,
-    ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, Box<dyn std::error::Error>> {
+    ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_operator_state_retriever =
@@ -284,17 +637,17 @@ impl AvsRegistryChainReader {
                 non_signer_operator_ids,
             )
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::CheckSignatureIndices))?;
         let OperatorStateRetriever::getCheckSignaturesIndicesReturn { _0: indices } =
             check_signature_indices;
         Ok(indices)
     }
 
-    /// Get Operator Id
-    pub async fn get_operator_id(
+    async fn get_operator_id(
         &self,
         operator_address: Address,
-    ) -> Result<FixedBytes<32>, Box<dyn std::error::Error>> {
+    ) -> Result<FixedBytes<32>, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_registry_coordinator =
@@ -303,16 +656,16 @@ impl AvsRegistryChainReader {
         let operator_id_return = contract_registry_coordinator
             .getOperatorId(operator_address)
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorId))?;
         let RegistryCoordinator::getOperatorIdReturn { _0: operator_id } = operator_id_return;
         Ok(operator_id)
     }
 
-    /// Get Operator from operator id
-    pub async fn get_operator_from_id(
+    async fn get_operator_from_id(
         &self,
         operator_id: [u8; 32],
-    ) -> Result<Address, Box<dyn std::error::Error>> {
+    ) -> Result<Address, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_registry_coordinator =
@@ -321,7 +674,8 @@ impl AvsRegistryChainReader {
         let operator_address_return = contract_registry_coordinator
             .getOperatorFromId(operator_id.into())
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorFromId))?;
 
         let RegistryCoordinator::getOperatorFromIdReturn {
             _0: operator_address,
@@ -329,11 +683,10 @@ impl AvsRegistryChainReader {
         Ok(operator_address)
     }
 
-    /// Check if operator is registered
-    pub async fn is_operator_registered(
+    async fn is_operator_registered(
         &self,
         operator_address: Address,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    ) -> Result<bool, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
         let contract_registry_coordinator =
@@ -342,7 +695,8 @@ impl AvsRegistryChainReader {
         let operator_status_return = contract_registry_coordinator
             .getOperatorStatus(operator_address)
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorStatus))?;
 
         let RegistryCoordinator::getOperatorStatusReturn {
             _0: operator_status,
@@ -351,107 +705,126 @@ impl AvsRegistryChainReader {
         Ok(operator_status == 1)
     }
 
-    /// Queies existing operators from for a particular block range
-    pub async fn query_existing_registered_operator_pub_keys(
+    async fn query_existing_registered_operator_pub_keys(
         &self,
         start_block: u64,
-        mut stop_block: u64,
-    ) -> Result<(Vec<Address>, Vec<OperatorPubKeys>), Box<dyn std::error::Error>> {
+        stop_block: u64,
+    ) -> Result<(Vec<Address>, Vec<OperatorPubKeys>), AvsRegistryError> {
         let provider = get_provider(&self.provider);
+        let stop_block = self.resolve_stop_block(stop_block).await?;
+
+        let filter = Filter::new()
+            .event("NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))")
+            .address(self.bls_apk_registry_addr);
+        let logs = self
+            .log_scanner
+            .scan(&provider, &filter, start_block, stop_block)
+            .await
+            .map_err(AvsRegistryError::GetEthLogs)?;
+        debug!(transactionLogs = ?logs, "avsRegistryChainReader.QueryExistingRegisteredOperatorPubKeys");
 
-        let query_block_range = 1024;
-        let current_block_number = provider.get_block_number().await?;
-        if stop_block.is_zero() {
-            stop_block = current_block_number;
-        }
-        let mut i = start_block;
         let mut operator_addresses: Vec<Address> = vec![];
         let mut operator_pub_keys: Vec<OperatorPubKeys> = vec![];
-        while i <= stop_block {
-            let mut to_block = i + (query_block_range - 1);
-            if to_block > stop_block {
-                to_block = stop_block;
-            }
-            let filter = Filter::new()
-                .select(i..to_block)
-                .event("NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))")
-                .address(self.bls_apk_registry_addr);
-
-            let logs = provider.get_logs(&filter).await?;
-            debug!(transactionLogs = ?logs, "avsRegistryChainReader.QueryExistingRegisteredOperatorPubKeys");
-
-            for v_log in logs.iter() {
-                let pub_key_reg_option = v_log
-                    .log_decode::<BLSApkRegistry::NewPubkeyRegistration>()
-                    .ok();
-                if let Some(pub_key_reg) = pub_key_reg_option {
-                    let data = pub_key_reg.data();
-                    let operator_addr = data.operator;
-                    operator_addresses.push(operator_addr);
-                    let g1_pub_key = data.pubkeyG1.clone();
-                    let g2_pub_key = data.pubkeyG2.clone();
-
-                    let operator_pub_key = OperatorPubKeys {
-                        g1_pub_key,
-                        g2_pub_key,
-                    };
-
-                    operator_pub_keys.push(operator_pub_key);
-                }
+        for v_log in logs.iter() {
+            let pub_key_reg_option = v_log
+                .log_decode::<BLSApkRegistry::NewPubkeyRegistration>()
+                .ok();
+            if let Some(pub_key_reg) = pub_key_reg_option {
+                let data = pub_key_reg.data();
+                operator_addresses.push(data.operator);
+                operator_pub_keys.push(OperatorPubKeys {
+                    g1_pub_key: data.pubkeyG1.clone(),
+                    g2_pub_key: data.pubkeyG2.clone(),
+                });
             }
-            i += 1024;
         }
         Ok((operator_addresses, operator_pub_keys))
     }
 
-    /// Query existing operator sockets
-    pub async fn query_existing_registered_operator_sockets(
+    async fn query_existing_registered_operator_sockets(
         &self,
         start_block: u64,
         stop_block: u64,
-    ) -> Result<HashMap<FixedBytes<32>, String>, Box<dyn std::error::Error>> {
+    ) -> Result<HashMap<FixedBytes<32>, String>, AvsRegistryError> {
         let provider = get_provider(&self.provider);
+        let stop_block = self.resolve_stop_block(stop_block).await?;
+
+        let filter = Filter::new()
+            .event("OperatorSocketUpdate(bytes32,string)")
+            .address(self.registry_coordinator_addr);
+        let logs = self
+            .log_scanner
+            .scan(&provider, &filter, start_block, stop_block)
+            .await
+            .map_err(AvsRegistryError::GetEthLogs)?;
 
         let mut operator_id_to_socket = HashMap::new();
+        for v_log in logs.iter() {
+            let socket_update_filter_option = v_log
+                .log_decode::<RegistryCoordinator::OperatorSocketUpdate>()
+                .ok();
+            if let Some(socket_update_filter) = socket_update_filter_option {
+                let data = socket_update_filter.data();
+                operator_id_to_socket.insert(data.operatorId, data.socket.clone());
+            }
+        }
 
-        let query_block_range = 10000;
+        Ok(operator_id_to_socket)
+    }
 
-        let mut i = start_block;
+    async fn query_operator_registration_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorRegistrationEvent>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let stop_block = self.resolve_stop_block(stop_block).await?;
+
+        let filter = Filter::new()
+            .events(OPERATOR_REGISTRATION_EVENTS)
+            .address(self.registry_coordinator_addr);
+        let logs = self
+            .log_scanner
+            .scan(&provider, &filter, start_block, stop_block)
+            .await
+            .map_err(AvsRegistryError::GetEthLogs)?;
 
-        while i <= stop_block {
-            let mut to_block = i + (query_block_range - 1);
-            if to_block > stop_block {
-                to_block = stop_block;
-            }
+        Ok(logs
+            .iter()
+            .filter_map(operator_registration_event)
+            .collect())
+    }
 
-            let mut filter = Filter::new()
-                .select(start_block..to_block)
-                .event("OperatorSocketUpdate(bytes32,string)")
-                .address(self.registry_coordinator_addr);
-            if stop_block == 0 {
-                let current_block_number = provider.get_block_number().await?;
-
-                filter = filter.clone().select(start_block..current_block_number);
-            };
-
-            let logs = provider.get_logs(&filter).await?;
-
-            for v_log in logs.iter() {
-                let socket_update_filter_option = v_log
-                    .log_decode::<RegistryCoordinator::OperatorSocketUpdate>()
-                    .ok();
-                if let Some(socket_update_filter) = socket_update_filter_option {
-                    let data = socket_update_filter.data();
-                    let operator_id = data.operatorId;
-                    let socket = &data.socket;
-                    operator_id_to_socket.insert(operator_id, socket.clone());
-                }
-            }
+    async fn query_operator_stake_update_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorStakeUpdateEvent>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let stop_block = self.resolve_stop_block(stop_block).await?;
+
+        let filter = Filter::new()
+            .event(OPERATOR_STAKE_UPDATE_EVENT)
+            .address(self.stake_registry_addr);
+        let logs = self
+            .log_scanner
+            .scan(&provider, &filter, start_block, stop_block)
+            .await
+            .map_err(AvsRegistryError::GetEthLogs)?;
 
-            i += query_block_range;
-        }
+        Ok(logs
+            .iter()
+            .filter_map(operator_stake_update_event)
+            .collect())
+    }
+}
 
-        Ok(operator_id_to_socket)
+fn stake_update(update: StakeRegistry::StakeUpdate) -> StakeUpdate {
+    StakeUpdate {
+        update_block_number: update.updateBlockNumber,
+        next_update_block_number: (update.nextUpdateBlockNumber != 0)
+            .then_some(update.nextUpdateBlockNumber),
+        stake: U256::from(update.stake),
     }
 }
 
@@ -459,6 +832,7 @@ impl AvsRegistryChainReader {
 mod tests {
 
     use super::*;
+    use eigen_testing_utils::anvil_constants;
     use hex::FromHex;
     use std::str::FromStr;
     const HOLESKY_REGISTRY_COORDINATOR: &str = "0x53012C69A189cfA2D9d29eb6F19B32e0A2EA3490";
@@ -477,11 +851,29 @@ mod tests {
             holesky_registry_coordinator,
             holesky_operator_state_retriever,
             holesky_provider.to_string(),
+            Some(17000),
         )
         .await
         .unwrap()
     }
 
+    #[tokio::test]
+    async fn test_new_with_wrong_chain_id() {
+        let err = AvsRegistryChainReader::new(
+            anvil_constants::get_registry_coordinator_address().await,
+            anvil_constants::get_operator_state_retriever_address().await,
+            "http://localhost:8545".to_string(),
+            Some(1),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "chain id mismatch: expected 1, provider returned 31337"
+        );
+    }
+
     #[tokio::test]
     async fn test_get_quorum_count() {
         let avs_reader = build_avs_registry_chain_reader().await;
@@ -489,6 +881,31 @@ mod tests {
         let _ = avs_reader.get_quorum_count().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_get_quorum_config() {
+        let avs_reader = build_avs_registry_chain_reader().await;
+
+        let config = avs_reader.get_quorum_config(0).await.unwrap();
+        assert_eq!(config.quorum_number, 0);
+        assert!(!config.strategies.is_empty());
+
+        let quorum_count = avs_reader.get_quorum_count().await.unwrap();
+        assert!(matches!(
+            avs_reader.get_quorum_config(quorum_count).await,
+            Err(AvsRegistryError::QuorumDoesNotExist)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_total_stake_history() {
+        let avs_reader = build_avs_registry_chain_reader().await;
+
+        let history = avs_reader.get_total_stake_history(0).await.unwrap();
+        assert!(history
+            .windows(2)
+            .all(|w| w[0].next_update_block_number == Some(w[1].update_block_number)));
+    }
+
     #[tokio::test]
     async fn test_get_operators_stake_in_quorums_at_block() {
         let avs_reader = build_avs_registry_chain_reader().await;
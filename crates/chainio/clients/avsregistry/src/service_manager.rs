@@ -0,0 +1,276 @@
+use crate::error::AvsRegistryError;
+use alloy_primitives::Address;
+use eigen_client_eth::chain::verify_chain_and_contracts;
+use eigen_client_eth::outcome::{send_with_outcome, TxOutcome};
+use eigen_client_eth::wallet::{PrivateKeyWallet, TxSender};
+use eigen_types::metadata::AvsMetadata;
+use eigen_utils::{binding::ServiceManagerBase, get_provider};
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// Time the metadata document of a metadata URI may take to be fetched
+pub const METADATA_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reads the strategies an AVS restakes from its `ServiceManagerBase`
+#[derive(Debug, Clone)]
+pub struct ServiceManagerChainReader {
+    service_manager_addr: Address,
+    provider: String,
+}
+
+impl ServiceManagerChainReader {
+    /// New ServiceManagerChainReader instance
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. The
+    /// service manager is checked to have code deployed.
+    pub async fn new(
+        service_manager_addr: Address,
+        provider: String,
+        expected_chain_id: Option<u64>,
+    ) -> Result<Self, AvsRegistryError> {
+        verify_chain_and_contracts(&provider, expected_chain_id, &[service_manager_addr]).await?;
+
+        Ok(ServiceManagerChainReader {
+            service_manager_addr,
+            provider,
+        })
+    }
+
+    /// Strategies an operator can restake in the AVS, over all its quorums
+    pub async fn get_restakeable_strategies(&self) -> Result<Vec<Address>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_service_manager = ServiceManagerBase::new(self.service_manager_addr, provider);
+        let ServiceManagerBase::getRestakeableStrategiesReturn { _0: strategies } =
+            contract_service_manager
+                .getRestakeableStrategies()
+                .call()
+                .await
+                .map_err(|e| {
+                    AvsRegistryError::from_call(e, AvsRegistryError::GetRestakeableStrategies)
+                })?;
+
+        Ok(strategies)
+    }
+
+    /// Strategies `operator` restakes in the AVS, those of the quorums it is registered
+    /// in. Empty if it is not registered.
+    pub async fn get_operator_restaked_strategies(
+        &self,
+        operator: Address,
+    ) -> Result<Vec<Address>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_service_manager = ServiceManagerBase::new(self.service_manager_addr, provider);
+        let ServiceManagerBase::getOperatorRestakedStrategiesReturn { _0: strategies } =
+            contract_service_manager
+                .getOperatorRestakedStrategies(operator)
+                .call()
+                .await
+                .map_err(|e| {
+                    AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorRestakedStrategies)
+                })?;
+
+        Ok(strategies)
+    }
+}
+
+/// Owner side of the `ServiceManagerBase` of an AVS: publishes its metadata URI.
+#[derive(Debug)]
+pub struct ServiceManagerWriter {
+    service_manager_addr: Address,
+    provider: String,
+    tx_sender: Arc<dyn TxSender>,
+}
+
+impl ServiceManagerWriter {
+    /// New ServiceManagerWriter instance signing the transactions with `signer`, the
+    /// owner of the service manager
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. The
+    /// service manager is checked to have code deployed.
+    pub async fn new(
+        service_manager_addr: Address,
+        provider: String,
+        signer: String,
+        expected_chain_id: Option<u64>,
+    ) -> Result<Self, AvsRegistryError> {
+        verify_chain_and_contracts(&provider, expected_chain_id, &[service_manager_addr]).await?;
+
+        Ok(ServiceManagerWriter {
+            service_manager_addr,
+            tx_sender: Arc::new(PrivateKeyWallet::new(signer, provider.clone())),
+            provider,
+        })
+    }
+
+    /// Sends the transactions through `tx_sender` instead of signing them with the
+    /// private key given to [`ServiceManagerWriter::new`].
+    pub fn with_tx_sender(mut self, tx_sender: Arc<dyn TxSender>) -> Self {
+        self.tx_sender = tx_sender;
+        self
+    }
+
+    /// Publish `metadata_uri` as the metadata URI of the AVS.
+    ///
+    /// The document at `metadata_uri` is fetched and validated first, nothing is sent if
+    /// it is not valid AVS metadata. Use
+    /// [`ServiceManagerWriter::update_avs_metadata_uri_unchecked`] for URIs that cannot
+    /// be fetched over http, e.g. `ipfs://` ones.
+    pub async fn update_avs_metadata_uri(
+        &self,
+        metadata_uri: String,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        info!(metadata_uri, "updating avs metadata uri");
+        let metadata = fetch_avs_metadata(&metadata_uri).await?;
+        info!(
+            metadata_uri,
+            name = metadata.name,
+            "fetched valid avs metadata"
+        );
+        self.update_avs_metadata_uri_unchecked(metadata_uri).await
+    }
+
+    /// Publish `metadata_uri` as the metadata URI of the AVS without fetching it. The
+    /// caller is responsible for the document being valid [`AvsMetadata`].
+    pub async fn update_avs_metadata_uri_unchecked(
+        &self,
+        metadata_uri: String,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_service_manager = ServiceManagerBase::new(self.service_manager_addr, provider);
+        let contract_call = contract_service_manager.updateAVSMetadataURI(metadata_uri.clone());
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::UpdateAvsMetadataUri),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, metadata_uri, "successfully updated avs metadata uri");
+        Ok(outcome)
+    }
+}
+
+/// Fetch the metadata document at `metadata_uri` over http and validate it, failing
+/// if it is not served within [`METADATA_FETCH_TIMEOUT`]
+pub async fn fetch_avs_metadata(metadata_uri: &str) -> Result<AvsMetadata, AvsRegistryError> {
+    let fetch_error = |source| AvsRegistryError::FetchMetadata {
+        uri: metadata_uri.to_string(),
+        source,
+    };
+    let client = reqwest::Client::builder()
+        .timeout(METADATA_FETCH_TIMEOUT)
+        .build()
+        .map_err(fetch_error)?;
+    let document = client
+        .get(metadata_uri)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(fetch_error)?
+        .text()
+        .await
+        .map_err(fetch_error)?;
+
+    document.parse().map_err(AvsRegistryError::InvalidMetadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ANVIL_HTTP_URL, OWNER_PRIVATE_KEY};
+    use eigen_testing_utils::anvil_constants;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const METADATA: &str = r#"{
+        "name": "Incredible Squaring",
+        "website": "https://www.eigenlayer.xyz",
+        "description": "Squares numbers",
+        "logo": "https://www.eigenlayer.xyz/logo.png"
+    }"#;
+
+    /// Serves `document` as `/metadata.json`, returning the server with its URI
+    async fn serve_metadata(document: &str) -> (MockServer, String) {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/metadata.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(document, "application/json"))
+            .mount(&server)
+            .await;
+        let uri = format!("{}/metadata.json", server.uri());
+        (server, uri)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_avs_metadata() {
+        let (_server, uri) = serve_metadata(METADATA).await;
+        let metadata = fetch_avs_metadata(&uri).await.unwrap();
+        assert_eq!(metadata.name, "Incredible Squaring");
+
+        let (_server, uri) = serve_metadata(r#"{"name": "AVS"}"#).await;
+        assert!(matches!(
+            fetch_avs_metadata(&uri).await,
+            Err(AvsRegistryError::InvalidMetadata(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_avs_metadata_uri() {
+        let _owner = crate::ANVIL_OWNER_LOCK.lock().await;
+        let service_manager = anvil_constants::get_service_manager_address().await;
+        let writer = ServiceManagerWriter::new(
+            service_manager,
+            ANVIL_HTTP_URL.to_string(),
+            OWNER_PRIVATE_KEY.to_string(),
+            Some(31337),
+        )
+        .await
+        .unwrap();
+
+        let (_server, uri) = serve_metadata(METADATA).await;
+        writer.update_avs_metadata_uri(uri).await.unwrap();
+
+        let (_server, uri) = serve_metadata(r#"{"name": "AVS"}"#).await;
+        assert!(matches!(
+            writer.update_avs_metadata_uri(uri).await,
+            Err(AvsRegistryError::InvalidMetadata(_))
+        ));
+
+        let ipfs_uri = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+        assert!(matches!(
+            writer.update_avs_metadata_uri(ipfs_uri.to_string()).await,
+            Err(AvsRegistryError::FetchMetadata { .. })
+        ));
+        writer
+            .update_avs_metadata_uri_unchecked(ipfs_uri.to_string())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restakeable_strategies() {
+        let service_manager = anvil_constants::get_service_manager_address().await;
+        let reader = ServiceManagerChainReader::new(
+            service_manager,
+            ANVIL_HTTP_URL.to_string(),
+            Some(31337),
+        )
+        .await
+        .unwrap();
+
+        let strategies = reader.get_restakeable_strategies().await.unwrap();
+        assert!(strategies.contains(&anvil_constants::get_erc20_mock_strategy().await));
+
+        let restaked = reader
+            .get_operator_restaked_strategies(Address::repeat_byte(1))
+            .await
+            .unwrap();
+        assert!(restaked.is_empty());
+    }
+}
@@ -1,24 +1,62 @@
+use crate::error::AvsRegistryError;
+use crate::events::{
+    operator_registration_event, operator_stake_update_event, OPERATOR_REGISTRATION_EVENTS,
+    OPERATOR_STAKE_UPDATE_EVENT,
+};
 use alloy_network::Ethereum;
 use alloy_primitives::Address;
 use alloy_provider::Provider;
 use alloy_provider::{
     fillers::{ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
-    RootProvider,
+    ProviderBuilder, RootProvider, WsConnect,
 };
-use alloy_rpc_types::Filter;
+use alloy_rpc_types::{Filter, Log};
+use async_trait::async_trait;
+use eigen_types::operator::{OperatorRegistrationEvent, OperatorStakeUpdateEvent};
 use eigen_utils::{binding::BLSApkRegistry, get_provider};
+use futures_util::{future, stream::BoxStream, StreamExt};
 use reqwest::Client;
+use std::fmt::Debug;
+use tracing::warn;
 
 /// AvsRegistry Chain Subscriber struct
 #[derive(Debug)]
 pub struct AvsRegistryChainSubscriber {
     provider: String,
+    ws_url: String,
+}
+
+/// Subscription to the AVS registry events, implemented by
+/// [`AvsRegistryChainSubscriber`].
+#[async_trait]
+pub trait AvsRegistrySubscriber: Debug + Send + Sync {
+    /// Filter of the pubkey registrations from the current block on
+    async fn get_new_pub_key_registration_filter(&self) -> Result<Filter, AvsRegistryError>;
+
+    /// Stream of the operator registrations and deregistrations in the RegistryCoordinator
+    /// at `registry_coordinator_addr`.
+    ///
+    /// Events of logs removed by a reorg are skipped, not reverted.
+    async fn subscribe_operator_registration_events(
+        &self,
+        registry_coordinator_addr: Address,
+    ) -> Result<BoxStream<'static, OperatorRegistrationEvent>, AvsRegistryError>;
+
+    /// Stream of the operator stake updates in the StakeRegistry at
+    /// `stake_registry_addr`.
+    ///
+    /// Events of logs removed by a reorg are skipped, not reverted.
+    async fn subscribe_operator_stake_update_events(
+        &self,
+        stake_registry_addr: Address,
+    ) -> Result<BoxStream<'static, OperatorStakeUpdateEvent>, AvsRegistryError>;
 }
 
 impl AvsRegistryChainSubscriber {
-    /// New avs registry subscriber instance
-    pub fn new(provider: String) -> Self {
-        AvsRegistryChainSubscriber { provider }
+    /// New avs registry subscriber instance, querying `provider` and subscribing to
+    /// events over the websocket `ws_url`
+    pub fn new(provider: String, ws_url: String) -> Self {
+        AvsRegistryChainSubscriber { provider, ws_url }
     }
 
     #[allow(clippy::type_complexity)]
@@ -42,18 +80,84 @@ impl AvsRegistryChainSubscriber {
 
         BLSApkRegistry::new(bls_apk_registry_addr, provider)
     }
+}
 
-    /// Utility function that returns new pubkey registration filter
-    pub async fn get_new_pub_key_registration_filter<'a>(
-        &self,
-    ) -> Result<Filter, Box<dyn std::error::Error>> {
+/// Stream of the logs matching `filter`, decoded with `decode`. Logs removed by a
+/// reorg and logs failing to decode are logged and skipped.
+async fn subscribe_events<E: Send + 'static>(
+    ws_url: &str,
+    filter: Filter,
+    decode: fn(&Log) -> Option<E>,
+) -> Result<BoxStream<'static, E>, AvsRegistryError> {
+    let provider = ProviderBuilder::new()
+        .on_ws(WsConnect::new(ws_url))
+        .await
+        .map_err(AvsRegistryError::SubscribeLogs)?;
+    let subscription = provider
+        .subscribe_logs(&filter)
+        .await
+        .map_err(AvsRegistryError::SubscribeLogs)?;
+
+    Ok(subscription
+        .into_stream()
+        .filter_map(move |log| {
+            // the websocket closes once the provider is dropped, the stream owns it
+            let _provider = &provider;
+            if log.removed {
+                warn!(
+                    block_number = ?log.block_number,
+                    transaction_hash = ?log.transaction_hash,
+                    "skipping log removed by a reorg"
+                );
+                return future::ready(None);
+            }
+            let event = decode(&log);
+            if event.is_none() {
+                warn!(
+                    block_number = ?log.block_number,
+                    transaction_hash = ?log.transaction_hash,
+                    topics = ?log.topics(),
+                    "failed to decode log"
+                );
+            }
+            future::ready(event)
+        })
+        .boxed())
+}
+
+#[async_trait]
+impl AvsRegistrySubscriber for AvsRegistryChainSubscriber {
+    async fn get_new_pub_key_registration_filter(&self) -> Result<Filter, AvsRegistryError> {
         let provider = get_provider(&self.provider);
 
-        let current_block_number = provider.get_block_number().await?;
+        let current_block_number = provider
+            .get_block_number()
+            .await
+            .map_err(AvsRegistryError::GetBlockNumber)?;
 
         let filter = Filter::new()
             .event("NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))")
             .from_block(current_block_number);
         Ok(filter)
     }
+
+    async fn subscribe_operator_registration_events(
+        &self,
+        registry_coordinator_addr: Address,
+    ) -> Result<BoxStream<'static, OperatorRegistrationEvent>, AvsRegistryError> {
+        let filter = Filter::new()
+            .events(OPERATOR_REGISTRATION_EVENTS)
+            .address(registry_coordinator_addr);
+        subscribe_events(&self.ws_url, filter, operator_registration_event).await
+    }
+
+    async fn subscribe_operator_stake_update_events(
+        &self,
+        stake_registry_addr: Address,
+    ) -> Result<BoxStream<'static, OperatorStakeUpdateEvent>, AvsRegistryError> {
+        let filter = Filter::new()
+            .event(OPERATOR_STAKE_UPDATE_EVENT)
+            .address(stake_registry_addr);
+        subscribe_events(&self.ws_url, filter, operator_stake_update_event).await
+    }
 }
@@ -1,30 +1,45 @@
+use crate::error::AvsRegistryError;
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
 use alloy_signer::SignerSync;
 use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::SolCall;
 use ark_bn254::G1Projective;
+use async_trait::async_trait;
 use eigen_chainio_utils::{
     convert_bn254_to_ark, convert_to_bn254_g1_point, convert_to_bn254_g2_point,
 };
-use eigen_client_elcontracts::reader::ELChainReader;
-use std::str::FromStr;
+use eigen_client_elcontracts::reader::{ELChainReader, ELReader};
+use eigen_client_eth::chain::verify_chain_and_contracts;
+use eigen_client_eth::outcome::{send_with_outcome, TxOutcome};
+use eigen_client_eth::simulation::{simulate, TxSimulation};
+use eigen_client_eth::wallet::{Eip712Signer, PrivateKeyWallet, TxSender};
+use std::fmt::Debug;
+use std::sync::Arc;
 
 use eigen_utils::binding::{
     BLSApkRegistry::{G1Point, PubkeyRegistrationParams},
+    OperatorStateRetriever,
     RegistryCoordinator::{
-        self, G1Point as RegistryG1Point, G2Point as RegistryG2Point,
+        self, G1Point as RegistryG1Point, G2Point as RegistryG2Point, OperatorKickParam,
         PubkeyRegistrationParams as RegistryPubkeyRegistrationParams,
     },
 };
 
-use alloy_primitives::{Address, Bytes, FixedBytes, TxHash, U256};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
 use eigen_crypto_bls::attestation::KeyPair;
+use eigen_types::{operator::QuorumOperatorSet, quorum::OperatorSetParams, socket::OperatorSocket};
 use tracing::info;
 use RegistryCoordinator::SignatureWithSaltAndExpiry;
 
 use eigen_utils::{
     binding::{ServiceManagerBase, StakeRegistry},
-    get_provider, get_signer,
+    get_provider,
 };
 
+/// Simulation of a transaction calling `C`, with its revert mapped to an error variant
+pub type Simulation<C> = TxSimulation<<C as SolCall>::Return, AvsRegistryError>;
+
 /// AvsRegistry Writer
 #[derive(Debug)]
 pub struct AvsRegistryChainWriter {
@@ -35,7 +50,65 @@ pub struct AvsRegistryChainWriter {
     bls_apk_registry_addr: Address,
     el_reader: ELChainReader,
     provider: String,
-    signer: String,
+    tx_sender: Arc<dyn TxSender>,
+    eip712_signer: Arc<dyn Eip712Signer>,
+}
+
+/// Write access to the AVS registry contracts, implemented by
+/// [`AvsRegistryChainWriter`].
+#[async_trait]
+pub trait AvsRegistryWriter: Debug + Send + Sync {
+    /// Register the operator in `quorum_numbers` with the registry coordinator
+    async fn register_operator_in_quorum_with_avs_registry_coordinator(
+        &self,
+        bls_key_pair: KeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+    ) -> Result<TxOutcome, AvsRegistryError>;
+
+    /// Register the operator in `quorum_numbers`, churning out `operator_kick_params`
+    /// from the quorums at their maximum operator count.
+    ///
+    /// `operator_kick_params` has one entry per quorum of `quorum_numbers`, see
+    /// [`AvsRegistryChainWriter::operator_kick_params`]. `churn_approver_signature` is
+    /// the churn approver signature of the digest of
+    /// [`AvsRegistryChainWriter::calculate_operator_churn_approval_digest_hash`] for
+    /// these kick params.
+    #[allow(clippy::too_many_arguments)]
+    async fn register_operator_with_churn(
+        &self,
+        bls_key_pair: KeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+        operator_kick_params: Vec<OperatorKickParam>,
+        churn_approver_signature: SignatureWithSaltAndExpiry,
+    ) -> Result<TxOutcome, AvsRegistryError>;
+
+    /// Update stakes of entire operator set for quorums
+    async fn update_stakes_of_entire_operator_set_for_quorums(
+        &self,
+        operators_per_quorum: Vec<Vec<Address>>,
+        quorum_number: Bytes,
+    ) -> Result<TxOutcome, AvsRegistryError>;
+
+    /// Update stakes of operator subset for all quorums
+    async fn update_stakes_of_operator_subset_for_all_quorums(
+        &self,
+        operators: Vec<Address>,
+    ) -> Result<TxOutcome, AvsRegistryError>;
+
+    /// Deregister the operator from `quorum_numbers`
+    async fn deregister_operator(
+        &self,
+        quorum_numbers: Bytes,
+    ) -> Result<TxOutcome, AvsRegistryError>;
+
+    /// Update the socket of the operator, see [`OperatorSocket`] for its format
+    async fn update_socket(&self, socket: String) -> Result<TxOutcome, AvsRegistryError>;
 }
 
 impl AvsRegistryChainWriter {
@@ -51,6 +124,7 @@ impl AvsRegistryChainWriter {
         provider: String,
         signer: String,
     ) -> Self {
+        let wallet = Arc::new(PrivateKeyWallet::new(signer, provider.clone()));
         AvsRegistryChainWriter {
             service_manager_addr,
             registry_coordinator_addr,
@@ -59,16 +133,43 @@ impl AvsRegistryChainWriter {
             bls_apk_registry_addr,
             el_reader,
             provider,
-            signer,
+            tx_sender: wallet.clone(),
+            eip712_signer: wallet,
         }
     }
 
+    /// Sends the transactions through `tx_sender` instead of signing them with the
+    /// private key given to [`AvsRegistryChainWriter::new`].
+    pub fn with_tx_sender(mut self, tx_sender: Arc<dyn TxSender>) -> Self {
+        self.tx_sender = tx_sender;
+        self
+    }
+
+    /// Signs the operator to AVS registration with `eip712_signer` instead of the
+    /// private key given to [`AvsRegistryChainWriter::new`]. It must hold the key of
+    /// the account sending the transactions.
+    pub fn with_eip712_signer(mut self, eip712_signer: Arc<dyn Eip712Signer>) -> Self {
+        self.eip712_signer = eip712_signer;
+        self
+    }
+
     /// build avs registry chain writer instance
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. Every
+    /// given and resolved contract address is checked to have code deployed.
     pub async fn build_avs_registry_chain_writer(
         &self,
         registry_coordinator_addr: Address,
         operator_state_retriever_addr: Address,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+        expected_chain_id: Option<u64>,
+    ) -> Result<Self, AvsRegistryError> {
+        verify_chain_and_contracts(
+            &self.provider,
+            expected_chain_id,
+            &[registry_coordinator_addr, operator_state_retriever_addr],
+        )
+        .await?;
+
         let provider = get_provider(&self.provider);
 
         let contract_registry_coordinator =
@@ -77,7 +178,8 @@ impl AvsRegistryChainWriter {
         let service_manager_addr = contract_registry_coordinator
             .serviceManager()
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetServiceManager))?;
         let RegistryCoordinator::serviceManagerReturn {
             _0: service_manager,
         } = service_manager_addr;
@@ -86,25 +188,51 @@ impl AvsRegistryChainWriter {
         let bls_apk_registry_addr = contract_registry_coordinator
             .blsApkRegistry()
             .call()
-            .await?;
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetBlsApkRegistry))?;
         let RegistryCoordinator::blsApkRegistryReturn {
             _0: bls_apk_registry,
         } = bls_apk_registry_addr;
-        let stake_registry_addr = contract_registry_coordinator.stakeRegistry().call().await?;
+        let stake_registry_addr = contract_registry_coordinator
+            .stakeRegistry()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetStakeRegistry))?;
         let RegistryCoordinator::stakeRegistryReturn { _0: stake_registry } = stake_registry_addr;
         let contract_stake_registry = StakeRegistry::new(stake_registry, &provider);
 
-        let delegation_manager_return = contract_stake_registry.delegation().call().await?;
+        let delegation_manager_return = contract_stake_registry
+            .delegation()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetDelegation))?;
 
         let StakeRegistry::delegationReturn {
             _0: delegation_manager_addr,
         } = delegation_manager_return;
-        let avs_directory_addr = contract_service_manager_base.avsDirectory().call().await?;
+        let avs_directory_addr = contract_service_manager_base
+            .avsDirectory()
+            .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetAvsRegistry))?;
 
         let ServiceManagerBase::avsDirectoryReturn { _0: avs_directory } = avs_directory_addr;
 
-        let el_reader =
-            ELChainReader::build(delegation_manager_addr, avs_directory, &self.provider).await?;
+        verify_chain_and_contracts(
+            &self.provider,
+            None,
+            &[service_manager, bls_apk_registry, stake_registry],
+        )
+        .await?;
+
+        let el_reader = ELChainReader::build(
+            delegation_manager_addr,
+            avs_directory,
+            &self.provider,
+            expected_chain_id,
+        )
+        .await
+        .map_err(AvsRegistryError::BuildElChainReader)?;
 
         Ok(AvsRegistryChainWriter {
             service_manager_addr: service_manager,
@@ -114,30 +242,349 @@ impl AvsRegistryChainWriter {
             bls_apk_registry_addr: bls_apk_registry,
             el_reader,
             provider: self.provider.clone(),
-            signer: self.signer.clone(),
+            tx_sender: self.tx_sender.clone(),
+            eip712_signer: self.eip712_signer.clone(),
         })
     }
 
-    /// Register operator in quorum with avs registry coordinator
-    pub async fn register_operator_in_quorum_with_avs_registry_coordinator(
+    /// Simulates `tx`, a call to `C`, from the account sending the transactions, mapping
+    /// a revert to its revert reason variant, or to [`AvsRegistryError::CustomError`] with the
+    /// data of a custom error.
+    async fn simulate<C: SolCall>(
+        &self,
+        tx: TransactionRequest,
+    ) -> Result<Simulation<C>, AvsRegistryError> {
+        let tx = tx.from(self.tx_sender.sender_address().await?);
+        let simulation = simulate::<C>(&self.provider, &tx)
+            .await
+            .map_err(AvsRegistryError::Simulate)?;
+        Ok(simulation.map_err(AvsRegistryError::from_simulated_revert))
+    }
+
+    async fn register_operator_tx(
+        &self,
+        bls_key_pair: &KeyPair,
+        operator_addr: Address,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+    ) -> Result<TransactionRequest, AvsRegistryError> {
+        let (pub_key_reg_params, operator_signature_with_salt_and_expiry) = self
+            .registration_params(
+                bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.registerOperator(
+            quorum_numbers,
+            socket,
+            pub_key_reg_params,
+            operator_signature_with_salt_and_expiry,
+        );
+
+        Ok(contract_call.gas(2000000).as_ref().clone())
+    }
+
+    /// Simulates
+    /// [`AvsRegistryWriter::register_operator_in_quorum_with_avs_registry_coordinator`]
+    /// at the pending block. The registration signatures are computed, but the
+    /// transaction is neither signed nor sent.
+    pub async fn simulate_register_operator_in_quorum_with_avs_registry_coordinator(
         &self,
         bls_key_pair: KeyPair,
         operator_to_avs_registration_sig_salt: FixedBytes<32>,
         operator_to_avs_registration_sig_expiry: U256,
         quorum_numbers: Bytes,
         socket: String,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
-        let provider = get_signer(self.signer.clone(), &self.provider);
-        let wallet = PrivateKeySigner::from_str(&self.signer).expect("failed to generate wallet ");
+    ) -> Result<Simulation<RegistryCoordinator::registerOperatorCall>, AvsRegistryError> {
+        let socket = parse_socket(&socket)?;
+        let operator_addr = self.operator_address().await?;
+        let tx = self
+            .register_operator_tx(
+                &bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+                quorum_numbers,
+                socket,
+            )
+            .await?;
 
-        // tracing info
-        info!(avs_service_manager = %self.service_manager_addr, operator= %wallet.address(),quorum_numbers = ?quorum_numbers,"quorum_numbers,registering operator with the AVS's registry coordinator");
+        self.simulate::<RegistryCoordinator::registerOperatorCall>(tx)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn register_operator_with_churn_tx(
+        &self,
+        bls_key_pair: &KeyPair,
+        operator_addr: Address,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+        operator_kick_params: Vec<OperatorKickParam>,
+        churn_approver_signature: SignatureWithSaltAndExpiry,
+    ) -> Result<TransactionRequest, AvsRegistryError> {
+        let (pub_key_reg_params, operator_signature_with_salt_and_expiry) = self
+            .registration_params(
+                bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let provider = get_provider(&self.provider);
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
-        let g1_hashes_msg_to_sign_return = contract_registry_coordinator
-            .pubkeyRegistrationMessageHash(wallet.address())
+        let contract_call = contract_registry_coordinator.registerOperatorWithChurn(
+            quorum_numbers,
+            socket,
+            pub_key_reg_params,
+            operator_kick_params,
+            churn_approver_signature,
+            operator_signature_with_salt_and_expiry,
+        );
+
+        // the gas limit is left to the provider's gas filler, as kicking operators makes
+        // the cost depend on the churned quorums
+        Ok(contract_call.as_ref().clone())
+    }
+
+    /// Simulates [`AvsRegistryWriter::register_operator_with_churn`] at the
+    /// pending block, without signing or sending the transaction
+    #[allow(clippy::too_many_arguments)]
+    pub async fn simulate_register_operator_with_churn(
+        &self,
+        bls_key_pair: KeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+        operator_kick_params: Vec<OperatorKickParam>,
+        churn_approver_signature: SignatureWithSaltAndExpiry,
+    ) -> Result<Simulation<RegistryCoordinator::registerOperatorWithChurnCall>, AvsRegistryError>
+    {
+        let socket = parse_socket(&socket)?;
+        let operator_addr = self.operator_address().await?;
+        let tx = self
+            .register_operator_with_churn_tx(
+                &bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+                quorum_numbers,
+                socket,
+                operator_kick_params,
+                churn_approver_signature,
+            )
+            .await?;
+
+        self.simulate::<RegistryCoordinator::registerOperatorWithChurnCall>(tx)
+            .await
+    }
+
+    /// Operators to churn out for `operator` to register in `quorum_numbers`, one per
+    /// quorum in the order of `quorum_numbers`.
+    ///
+    /// For each quorum at its maximum operator count, it is the lowest stake operator,
+    /// if `operator` has enough stake to churn it out. For the other quorums, it is the
+    /// zero address, which the registry coordinator ignores.
+    pub async fn operator_kick_params(
+        &self,
+        operator: Address,
+        quorum_numbers: Bytes,
+    ) -> Result<Vec<OperatorKickParam>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let current_block = provider
+            .get_block_number()
+            .await
+            .map_err(AvsRegistryError::GetBlockNumber)?;
+        let current_block =
+            u32::try_from(current_block).map_err(|_| AvsRegistryError::BlockNumberOverflow)?;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, &provider);
+        let contract_operator_state_retriever =
+            OperatorStateRetriever::new(self.operator_state_retriever_addr, &provider);
+
+        let OperatorStateRetriever::getOperatorState_0Return { _0: operator_state } =
+            contract_operator_state_retriever
+                .getOperatorState_0(
+                    self.registry_coordinator_addr,
+                    quorum_numbers.clone(),
+                    current_block,
+                )
+                .call()
+                .await
+                .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorState))?;
+        let operator_set = QuorumOperatorSet::from_operator_state(&quorum_numbers, operator_state);
+
+        let mut operator_kick_params = Vec::with_capacity(quorum_numbers.len());
+        for quorum_number in quorum_numbers.iter().copied() {
+            let RegistryCoordinator::getOperatorSetParamsReturn { _0: params } =
+                contract_registry_coordinator
+                    .getOperatorSetParams(quorum_number)
+                    .call()
+                    .await
+                    .map_err(|e| {
+                        AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorSetParams)
+                    })?;
+            let params = OperatorSetParams::from(params);
+            let operators = operator_set.operators(quorum_number);
+            if !params.is_full(operators.len()) {
+                operator_kick_params.push(OperatorKickParam {
+                    quorumNumber: quorum_number,
+                    operator: Address::ZERO,
+                });
+                continue;
+            }
+
+            let StakeRegistry::weightOfOperatorForQuorumReturn { _0: weight } =
+                contract_stake_registry
+                    .weightOfOperatorForQuorum(quorum_number, operator)
+                    .call()
+                    .await
+                    .map_err(|e| {
+                        AvsRegistryError::from_call(e, AvsRegistryError::GetOperatorWeight)
+                    })?;
+            let operator_to_kick = params
+                .operator_to_kick(operators, U256::from(weight))
+                .ok_or(AvsRegistryError::ChurnNotPossible { quorum_number })?;
+            operator_kick_params.push(OperatorKickParam {
+                quorumNumber: quorum_number,
+                operator: operator_to_kick.operator,
+            });
+        }
+        Ok(operator_kick_params)
+    }
+
+    /// Digest the churn approver signs to approve `registering_operator` churning out
+    /// `operator_kick_params`
+    pub async fn calculate_operator_churn_approval_digest_hash(
+        &self,
+        registering_operator: Address,
+        registering_operator_id: FixedBytes<32>,
+        operator_kick_params: Vec<OperatorKickParam>,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<FixedBytes<32>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let RegistryCoordinator::calculateOperatorChurnApprovalDigestHashReturn { _0: digest } =
+            contract_registry_coordinator
+                .calculateOperatorChurnApprovalDigestHash(
+                    registering_operator,
+                    registering_operator_id,
+                    operator_kick_params,
+                    salt,
+                    expiry,
+                )
+                .call()
+                .await
+                .map_err(|e| {
+                    AvsRegistryError::from_call(
+                        e,
+                        AvsRegistryError::CalculateOperatorChurnApprovalDigestHash,
+                    )
+                })?;
+        Ok(digest)
+    }
+
+    /// Churn approver signature of `registering_operator` churning out
+    /// `operator_kick_params`, for when `churn_approver` holds the key of the churn
+    /// approver of the registry coordinator.
+    ///
+    /// `registering_operator_id` is the operator id of the BLS key the operator
+    /// registers with, see [`eigen_types::operator::operator_id_from_g1_pub_key`].
+    pub async fn sign_operator_churn_approval(
+        &self,
+        churn_approver: &PrivateKeySigner,
+        registering_operator: Address,
+        registering_operator_id: FixedBytes<32>,
+        operator_kick_params: Vec<OperatorKickParam>,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<SignatureWithSaltAndExpiry, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let RegistryCoordinator::churnApproverReturn {
+            _0: churn_approver_addr,
+        } = contract_registry_coordinator
+            .churnApprover()
             .call()
+            .await
+            .map_err(|e| AvsRegistryError::from_call(e, AvsRegistryError::GetChurnApprover))?;
+        if churn_approver.address() != churn_approver_addr {
+            return Err(AvsRegistryError::ChurnApproverMismatch {
+                signer: churn_approver.address(),
+                churn_approver: churn_approver_addr,
+            });
+        }
+
+        let digest = self
+            .calculate_operator_churn_approval_digest_hash(
+                registering_operator,
+                registering_operator_id,
+                operator_kick_params,
+                salt,
+                expiry,
+            )
             .await?;
+        let signature = churn_approver
+            .sign_hash_sync(&digest)
+            .map_err(AvsRegistryError::SignChurnApproval)?;
+        Ok(SignatureWithSaltAndExpiry {
+            signature: signature.as_bytes().into(),
+            salt,
+            expiry,
+        })
+    }
+
+    /// Address of the operator sending the transactions, checked to be the EIP-712
+    /// signer
+    async fn operator_address(&self) -> Result<Address, AvsRegistryError> {
+        let operator_addr = self.tx_sender.sender_address().await?;
+        let signer_addr = self.eip712_signer.signer_address().await?;
+        if signer_addr != operator_addr {
+            return Err(AvsRegistryError::SignerMismatch {
+                signer: signer_addr,
+                operator: operator_addr,
+            });
+        }
+        Ok(operator_addr)
+    }
+
+    /// Pubkey registration params of `bls_key_pair` and operator to AVS registration
+    /// signature of `operator_addr`
+    async fn registration_params(
+        &self,
+        bls_key_pair: &KeyPair,
+        operator_addr: Address,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+    ) -> Result<(RegistryPubkeyRegistrationParams, SignatureWithSaltAndExpiry), AvsRegistryError>
+    {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let g1_hashes_msg_to_sign_return = contract_registry_coordinator
+            .pubkeyRegistrationMessageHash(operator_addr)
+            .call()
+            .await
+            .map_err(|e| {
+                AvsRegistryError::from_call(e, AvsRegistryError::PubKeyRegistrationMessageHash)
+            })?;
         let RegistryCoordinator::pubkeyRegistrationMessageHashReturn {
             _0: g1_hashes_msg_to_sign,
         } = g1_hashes_msg_to_sign_return;
@@ -166,19 +613,37 @@ impl AvsRegistryChainWriter {
             pubkeyG2: g2_pubkey_bn254,
         };
 
-        let msg_to_sign = self
+        let digest = self
             .el_reader
             .calculate_operator_avs_registration_digest_hash(
-                wallet.address(),
+                operator_addr,
                 self.service_manager_addr,
                 operator_to_avs_registration_sig_salt,
                 operator_to_avs_registration_sig_expiry,
             )
-            .await?;
+            .await
+            .map_err(AvsRegistryError::CalculateOperatorAvsRegistrationDigestHash)?;
+        let typed_data = self
+            .el_reader
+            .operator_avs_registration_typed_data(
+                operator_addr,
+                self.service_manager_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await
+            .map_err(AvsRegistryError::CalculateOperatorAvsRegistrationDigestHash)?;
+        let typed_data_hash = typed_data
+            .eip712_signing_hash()
+            .map_err(AvsRegistryError::TypedDataHash)?;
+        if typed_data_hash != digest {
+            return Err(AvsRegistryError::TypedDataDigestMismatch {
+                typed_data_hash,
+                digest,
+            });
+        }
 
-        let operator_signature = wallet
-            .sign_message_sync(msg_to_sign.as_slice())
-            .expect("failed to sign message");
+        let operator_signature = self.eip712_signer.sign_typed_data(&typed_data).await?;
 
         let operator_signature_with_salt_and_expiry = SignatureWithSaltAndExpiry {
             signature: operator_signature.as_bytes().into(),
@@ -186,9 +651,7 @@ impl AvsRegistryChainWriter {
             expiry: operator_to_avs_registration_sig_expiry,
         };
 
-        let contract_call = contract_registry_coordinator.registerOperator(
-            quorum_numbers.clone(),
-            socket,
+        Ok((
             RegistryPubkeyRegistrationParams {
                 pubkeyRegistrationSignature: RegistryG1Point {
                     X: pub_key_reg_params.pubkeyRegistrationSignature.X,
@@ -204,73 +667,312 @@ impl AvsRegistryChainWriter {
                 },
             },
             operator_signature_with_salt_and_expiry,
-        );
+        ))
+    }
+
+    /// Simulates
+    /// [`AvsRegistryWriter::update_stakes_of_entire_operator_set_for_quorums`] at
+    /// the pending block, without signing or sending the transaction
+    pub async fn simulate_update_stakes_of_entire_operator_set_for_quorums(
+        &self,
+        operators_per_quorum: Vec<Vec<Address>>,
+        quorum_number: Bytes,
+    ) -> Result<Simulation<RegistryCoordinator::updateOperatorsForQuorumCall>, AvsRegistryError>
+    {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator
+            .updateOperatorsForQuorum(operators_per_quorum, quorum_number);
+
+        self.simulate::<RegistryCoordinator::updateOperatorsForQuorumCall>(
+            contract_call.as_ref().clone(),
+        )
+        .await
+    }
+
+    /// Simulates
+    /// [`AvsRegistryWriter::update_stakes_of_operator_subset_for_all_quorums`] at
+    /// the pending block, without signing or sending the transaction
+    pub async fn simulate_update_stakes_of_operator_subset_for_all_quorums(
+        &self,
+        operators: Vec<Address>,
+    ) -> Result<Simulation<RegistryCoordinator::updateOperatorsCall>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.updateOperators(operators);
+
+        self.simulate::<RegistryCoordinator::updateOperatorsCall>(contract_call.as_ref().clone())
+            .await
+    }
+
+    /// Simulates [`AvsRegistryWriter::deregister_operator`] at the pending block,
+    /// without signing or sending the transaction
+    pub async fn simulate_deregister_operator(
+        &self,
+        quorum_numbers: Bytes,
+    ) -> Result<Simulation<RegistryCoordinator::deregisterOperatorCall>, AvsRegistryError> {
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.deregisterOperator(quorum_numbers);
+
+        self.simulate::<RegistryCoordinator::deregisterOperatorCall>(contract_call.as_ref().clone())
+            .await
+    }
+
+    /// Simulates [`AvsRegistryWriter::update_socket`] at the pending block,
+    /// without signing or sending the transaction
+    pub async fn simulate_update_socket(
+        &self,
+        socket: String,
+    ) -> Result<Simulation<RegistryCoordinator::updateSocketCall>, AvsRegistryError> {
+        let socket = parse_socket(&socket)?;
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.updateSocket(socket);
+
+        self.simulate::<RegistryCoordinator::updateSocketCall>(contract_call.as_ref().clone())
+            .await
+    }
+}
+
+/// `socket` in its canonical form, rejecting malformed sockets before sending a
+/// transaction
+fn parse_socket(socket: &str) -> Result<String, AvsRegistryError> {
+    socket
+        .parse::<OperatorSocket>()
+        .map(|socket| socket.to_string())
+        .map_err(AvsRegistryError::InvalidSocket)
+}
+
+#[async_trait]
+impl AvsRegistryWriter for AvsRegistryChainWriter {
+    async fn register_operator_in_quorum_with_avs_registry_coordinator(
+        &self,
+        bls_key_pair: KeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        let socket = parse_socket(&socket)?;
+        let operator_addr = self.operator_address().await?;
+
+        // tracing info
+        info!(avs_service_manager = %self.service_manager_addr, operator= %operator_addr,quorum_numbers = ?quorum_numbers,"quorum_numbers,registering operator with the AVS's registry coordinator");
+        let tx = self
+            .register_operator_tx(
+                &bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+                quorum_numbers.clone(),
+                socket,
+            )
+            .await?;
 
-        let tx_call = contract_call.gas(2000000);
-        let tx = tx_call.send().await?;
+        let outcome = send_with_outcome(self.tx_sender.as_ref(), &self.provider, &tx, |e| {
+            AvsRegistryError::from_call(e, AvsRegistryError::RegisterOperator)
+        })
+        .await?;
 
         // tracing info
-        info!(tx_hash = %tx.tx_hash(), avs_service_manager = %self.service_manager_addr,operator = %wallet.address(),quorum_numbers = ?quorum_numbers , "successfully registered operator with AVS registry coordinator");
-        Ok(*tx.tx_hash())
+        info!(tx_hash = %outcome.tx_hash, avs_service_manager = %self.service_manager_addr,operator = %operator_addr,quorum_numbers = ?quorum_numbers , "successfully registered operator with AVS registry coordinator");
+        Ok(outcome)
     }
 
-    /// Update stakes of entire operator set for quorums
-    pub async fn update_stakes_of_entire_operator_set_for_quorums(
+    #[allow(clippy::too_many_arguments)]
+    async fn register_operator_with_churn(
+        &self,
+        bls_key_pair: KeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+        operator_kick_params: Vec<OperatorKickParam>,
+        churn_approver_signature: SignatureWithSaltAndExpiry,
+    ) -> Result<TxOutcome, AvsRegistryError> {
+        let socket = parse_socket(&socket)?;
+        let operator_addr = self.operator_address().await?;
+
+        info!(avs_service_manager = %self.service_manager_addr, operator = %operator_addr, quorum_numbers = ?quorum_numbers, operators_to_kick = ?operator_kick_params.iter().map(|param| param.operator).collect::<Vec<_>>(), "registering operator with churn with the AVS's registry coordinator");
+        let tx = self
+            .register_operator_with_churn_tx(
+                &bls_key_pair,
+                operator_addr,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+                quorum_numbers.clone(),
+                socket,
+                operator_kick_params,
+                churn_approver_signature,
+            )
+            .await?;
+
+        let outcome = send_with_outcome(self.tx_sender.as_ref(), &self.provider, &tx, |e| {
+            AvsRegistryError::from_call(e, AvsRegistryError::RegisterOperatorWithChurn)
+        })
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, avs_service_manager = %self.service_manager_addr, operator = %operator_addr, quorum_numbers = ?quorum_numbers, "successfully registered operator with churn with AVS registry coordinator");
+        Ok(outcome)
+    }
+
+    async fn update_stakes_of_entire_operator_set_for_quorums(
         &self,
         operators_per_quorum: Vec<Vec<Address>>,
         quorum_number: Bytes,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
+    ) -> Result<TxOutcome, AvsRegistryError> {
         info!(quorum_numbers = %quorum_number, "updating stakes for entire operator set");
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = get_provider(&self.provider);
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let contract_call = contract_registry_coordinator
             .updateOperatorsForQuorum(operators_per_quorum, quorum_number.clone());
 
-        let tx = contract_call.send().await?;
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::UpdateOperatorForQuorum),
+        )
+        .await?;
 
         // tracing info
-        info!(tx_hash = ?tx, quorum_numbers = %quorum_number,"succesfully updated stakes for entire operator set" );
-        return Ok(*tx.tx_hash());
+        info!(tx_hash = %outcome.tx_hash, quorum_numbers = %quorum_number,"succesfully updated stakes for entire operator set" );
+        Ok(outcome)
     }
 
-    /// Update stakes of operator subset for all quorums
-    pub async fn update_stakes_of_operator_subset_for_all_quorums(
+    async fn update_stakes_of_operator_subset_for_all_quorums(
         &self,
         operators: Vec<Address>,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
+    ) -> Result<TxOutcome, AvsRegistryError> {
         info!(operators = ?operators, "updating stakes of operator subset for all quorums");
 
-        let provider = get_signer(self.signer.clone(), &self.provider);
-
+        let provider = get_provider(&self.provider);
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let contract_call = contract_registry_coordinator.updateOperators(operators);
 
-        let tx = contract_call.send().await?;
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::UpdateStakeForAllQuorums),
+        )
+        .await?;
 
-        info!(tx_hash = ?tx,"ssuccesfully updated stakes of operator subset for all quorums" );
-        Ok(*tx.tx_hash())
+        info!(tx_hash = %outcome.tx_hash,"ssuccesfully updated stakes of operator subset for all quorums" );
+        Ok(outcome)
     }
 
-    /// Deregister operator
-    pub async fn deregister_operator(
+    async fn deregister_operator(
         &self,
         quorum_numbers: Bytes,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
+    ) -> Result<TxOutcome, AvsRegistryError> {
         info!("deregistering operator with the AVS's registry coordinator");
-        let provider = get_signer(self.signer.clone(), &self.provider);
-
+        let provider = get_provider(&self.provider);
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let contract_call = contract_registry_coordinator.deregisterOperator(quorum_numbers);
 
-        let tx = contract_call.send().await?;
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::DeregisterOperator),
+        )
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash,"succesfully deregistered operator with the AVS's registry coordinator" );
+        Ok(outcome)
+    }
+
+    async fn update_socket(&self, socket: String) -> Result<TxOutcome, AvsRegistryError> {
+        let socket = parse_socket(&socket)?;
+        info!(socket = %socket, "updating operator socket with the AVS's registry coordinator");
+        let provider = get_provider(&self.provider);
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let contract_call = contract_registry_coordinator.updateSocket(socket.clone());
+
+        let outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            contract_call.as_ref(),
+            |e| AvsRegistryError::from_call(e, AvsRegistryError::UpdateSocket),
+        )
+        .await?;
 
-        info!(tx_hash = ?tx,"succesfully deregistered operator with the AVS's registry coordinator" );
-        return Ok(*tx.tx_hash());
+        info!(tx_hash = %outcome.tx_hash, socket = %socket, "successfully updated operator socket with the AVS's registry coordinator");
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OWNER_PRIVATE_KEY;
+    use alloy_sol_types::{sol, SolError};
+    use eigen_testing_utils::mock_rpc::mock_rpc;
+    use serde_json::json;
+
+    sol! {
+        /// Registry coordinator reverting with custom errors
+        interface CustomRegistryCoordinator {
+            error QuorumFull(uint8 quorum);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_simulate_custom_error() {
+        let data = CustomRegistryCoordinator::QuorumFull { quorum: 1 }.abi_encode();
+        let server = mock_rpc(vec![(
+            "eth_call",
+            Err(json!({
+                "code": 3,
+                "message": "execution reverted",
+                "data": Bytes::from(data.clone()).to_string(),
+            })),
+        )])
+        .await;
+        let el_reader =
+            ELChainReader::new(Address::ZERO, Address::ZERO, Address::ZERO, server.uri());
+        let writer = AvsRegistryChainWriter::new(
+            Address::ZERO,
+            Address::repeat_byte(1),
+            Address::ZERO,
+            Address::ZERO,
+            Address::ZERO,
+            el_reader,
+            server.uri(),
+            OWNER_PRIVATE_KEY.to_string(),
+        )
+        .await;
+
+        let simulation = writer
+            .simulate_update_socket("localhost:8080".to_string())
+            .await
+            .unwrap();
+        assert_eq!(simulation.gas_estimate, None);
+        let error = simulation.result.unwrap_err();
+        assert!(matches!(&error, AvsRegistryError::CustomError(reverted) if *reverted == data));
+        assert!(matches!(
+            error
+                .decode_custom_error::<CustomRegistryCoordinator::CustomRegistryCoordinatorErrors>(
+                ),
+            Some(
+                CustomRegistryCoordinator::CustomRegistryCoordinatorErrors::QuorumFull(
+                    CustomRegistryCoordinator::QuorumFull { quorum: 1 }
+                )
+            )
+        ));
     }
 }
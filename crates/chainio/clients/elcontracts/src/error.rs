@@ -1,68 +1,209 @@
+use alloy_primitives::{Bytes, FixedBytes};
+use alloy_sol_types::SolInterface;
+use alloy_transport::TransportError;
+use eigen_client_eth::chain::ChainError;
+use eigen_client_eth::outcome::TxReverted;
+use eigen_client_eth::simulation::{SimulatedRevert, SimulationError};
+use eigen_client_eth::wallet::WalletError;
+use eigen_utils::revert::revert_reason;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ElContractsError {
     /// Get slasher address
     #[error("failed to get slasher address")]
-    GetSlasher,
+    GetSlasher(#[source] alloy_contract::Error),
 
     /// Get strategy manager
     #[error("Failed to get strategy manager address")]
-    GetStrategyManager,
+    GetStrategyManager(#[source] alloy_contract::Error),
 
     /// Get delegation approval digest hash
     #[error("Failed to get delegation approval digest hash")]
-    GetDelegationApprovalDigestHash,
+    GetDelegationApprovalDigestHash(#[source] alloy_contract::Error),
 
     /// Get Operator avs registration digest hash
     #[error("Failed to get operator avs registration digest hash")]
-    GetOperatorAvsRegistrationDigestHash,
+    GetOperatorAvsRegistrationDigestHash(#[source] alloy_contract::Error),
 
     /// Get Operator shares
     #[error("Failed to get oeprator shares")]
-    GetOperatorShares,
+    GetOperatorShares(#[source] alloy_contract::Error),
 
     /// Is frozen
     #[error("failed to get operator frozen status ")]
-    IsFrozen,
+    IsFrozen(#[source] alloy_contract::Error),
 
     /// service_manager_can_slash_operator_until_block
     #[error("Failed to get service manager slashing expiry")]
-    ServiceManagerCanSlashOperatorExpiry,
+    ServiceManagerCanSlashOperatorExpiry(#[source] alloy_contract::Error),
 
     /// Get underlying token
     #[error("Failed to get underlying token")]
-    GetUnderlyingToken,
+    GetUnderlyingToken(#[source] alloy_contract::Error),
 
     /// Get operator details
     #[error("Failed to get oeprator details")]
-    GetOperatorDetails,
+    GetOperatorDetails(#[source] alloy_contract::Error),
 
     /// is operator or not
     #[error("Is operator or not ")]
-    IsOperator,
+    IsOperator(#[source] alloy_contract::Error),
 
     /// registering as operator
     #[error("Failed to register as a operator")]
-    RegisterAsOperator,
+    RegisterAsOperator(#[source] WalletError),
 
     /// modify operator details
     #[error("modify operator details")]
-    ModifyOperatorDetails,
-
-    /// get strategy and underlying erc20 token
-    #[error("get strategy and underlying erc20 token")]
-    GetStrategyAndUnderlyingERC20Token,
+    ModifyOperatorDetails(#[source] WalletError),
 
     /// approve to underlying token
     #[error("Failed to call approve in underlying token contract")]
-    ApproveCallToUnderlyingToken,
+    ApproveCallToUnderlyingToken(#[source] WalletError),
 
     /// deposit into strategy call
     #[error("Failed to deposit into strategy")]
-    DepositIntoStrategy,
+    DepositIntoStrategy(#[source] WalletError),
 
     /// update metadata uri
     #[error("Failed to update metadata uri")]
-    UpdateMetadataUri,
+    UpdateMetadataUri(#[source] WalletError),
+
+    /// Failed to simulate a transaction
+    #[error("Failed to simulate transaction")]
+    Simulate(#[source] SimulationError),
+
+    /// Transaction was mined but reverted
+    #[error(transparent)]
+    TransactionReverted(#[from] Box<TxReverted>),
+
+    /// Wallet failed to get its address or sign
+    #[error("wallet error")]
+    Wallet(#[from] WalletError),
+
+    /// Get chain id
+    #[error("Failed to get chain id")]
+    GetChainId(#[source] TransportError),
+
+    /// Provider is not connected to the expected chain or contracts
+    #[error(transparent)]
+    Chain(#[from] ChainError),
+
+    /// Failed to hash the typed data
+    #[error("failed to hash typed data")]
+    TypedDataHash(#[source] alloy_dyn_abi::Error),
+
+    /// Signing hash of the typed data differs from the digest computed by the contract
+    #[error("typed data hash {typed_data_hash} differs from contract digest {digest}")]
+    TypedDataDigestMismatch {
+        typed_data_hash: FixedBytes<32>,
+        digest: FixedBytes<32>,
+    },
+
+    /// Operator is already registered in EigenLayer
+    #[error("operator is already registered")]
+    OperatorAlreadyRegistered,
+
+    /// Caller is not an operator registered in EigenLayer
+    #[error("caller is not an operator")]
+    NotAnOperator,
+
+    /// Staker is already delegated to an operator
+    #[error("staker is already delegated")]
+    StakerAlreadyDelegated,
+
+    /// Signature is past its expiry
+    #[error("signature expired")]
+    SignatureExpired,
+
+    /// Salt of the signature has already been used
+    #[error("signature salt already spent")]
+    SaltAlreadySpent,
+
+    /// Strategy is not whitelisted for deposits
+    #[error("strategy is not whitelisted for deposit")]
+    StrategyNotWhitelisted,
+
+    /// Called functionality is paused
+    #[error("functionality is paused")]
+    Paused,
+
+    /// Contract reverted with a reason not mapped to a variant
+    #[error("contract reverted: {0}")]
+    Reverted(String),
+
+    /// Contract reverted with a custom error, given as its selector and arguments, or
+    /// without data
+    #[error("contract reverted with custom error {0}")]
+    CustomError(Bytes),
+}
+
+impl ElContractsError {
+    /// Variant of a contract revert with `reason`
+    pub fn from_revert_reason(reason: String) -> Self {
+        let (_, message) = reason.split_once(": ").unwrap_or(("", &reason));
+        match message {
+            "operator has already registered" => Self::OperatorAlreadyRegistered,
+            "caller must be an operator" => Self::NotAnOperator,
+            "staker is already actively delegated" => Self::StakerAlreadyDelegated,
+            "approver signature expired" | "staker signature expired" => Self::SignatureExpired,
+            "approverSalt already spent" => Self::SaltAlreadySpent,
+            "strategy not whitelisted" => Self::StrategyNotWhitelisted,
+            "index is paused" => Self::Paused,
+            _ => Self::Reverted(reason),
+        }
+    }
+
+    /// Variant of the revert of a simulated transaction
+    pub fn from_simulated_revert(revert: SimulatedRevert) -> Self {
+        match revert {
+            SimulatedRevert::Reason(reason) => Self::from_revert_reason(reason),
+            SimulatedRevert::Data(data) => Self::CustomError(data),
+        }
+    }
+
+    /// Custom error of a [`Self::CustomError`], decoded against `E`, the errors of the
+    /// reverting contract
+    pub fn decode_custom_error<E: SolInterface>(&self) -> Option<E> {
+        match self {
+            Self::CustomError(data) => E::abi_decode(data, true).ok(),
+            _ => None,
+        }
+    }
+
+    /// Error of a failed call: the variant of its revert reason if it reverted, else
+    /// `error` wrapping `source`.
+    pub(crate) fn from_call<E>(source: E, error: impl FnOnce(E) -> Self) -> Self
+    where
+        E: std::error::Error + 'static,
+    {
+        match revert_reason(&source) {
+            Some(reason) => Self::from_revert_reason(reason),
+            None => error(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_revert_reason() {
+        assert!(matches!(
+            ElContractsError::from_revert_reason(
+                "DelegationManager.registerAsOperator: operator has already registered".into()
+            ),
+            ElContractsError::OperatorAlreadyRegistered
+        ));
+        assert!(matches!(
+            ElContractsError::from_revert_reason("Pausable: index is paused".into()),
+            ElContractsError::Paused
+        ));
+        assert!(matches!(
+            ElContractsError::from_revert_reason("ERC20: insufficient allowance".into()),
+            ElContractsError::Reverted(reason) if reason == "ERC20: insufficient allowance"
+        ));
+    }
 }
@@ -8,4 +8,5 @@
 
 pub mod error;
 pub mod reader;
+pub mod typed_data;
 pub mod writer;
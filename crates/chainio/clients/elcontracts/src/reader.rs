@@ -1,9 +1,16 @@
+use crate::error::ElContractsError;
+use crate::typed_data::{delegation_approval_typed_data, operator_avs_registration_typed_data};
+use alloy_dyn_abi::TypedData;
 use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_provider::Provider;
+use async_trait::async_trait;
+use eigen_client_eth::chain::verify_chain_and_contracts;
 use eigen_types::operator::Operator;
 use eigen_utils::{
     binding::{AVSDirectory, DelegationManager, ISlasher, IStrategy, IERC20},
     get_provider,
 };
+use std::fmt::Debug;
 
 #[derive(Debug, Clone)]
 pub struct ELChainReader {
@@ -13,6 +20,79 @@ pub struct ELChainReader {
     provider: String,
 }
 
+/// Read access to the EigenLayer core contracts, implemented by [`ELChainReader`].
+#[async_trait]
+pub trait ELReader: Debug + Send + Sync {
+    /// Digest the delegation approver signs to let `staker` delegate to `operator`
+    async fn calculate_delegation_approval_digest_hash(
+        &self,
+        staker: Address,
+        operator: Address,
+        delegation_approver: Address,
+        approve_salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<FixedBytes<32>, ElContractsError>;
+
+    /// Digest `operator` signs to register to `avs`
+    async fn calculate_operator_avs_registration_digest_hash(
+        &self,
+        operator: Address,
+        avs: Address,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<FixedBytes<32>, ElContractsError>;
+
+    /// Typed data `delegation_approver` signs to let `staker` delegate to `operator`,
+    /// hashing to [`ELReader::calculate_delegation_approval_digest_hash`]
+    async fn delegation_approval_typed_data(
+        &self,
+        staker: Address,
+        operator: Address,
+        delegation_approver: Address,
+        approve_salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<TypedData, ElContractsError>;
+
+    /// Typed data `operator` signs to register to `avs`, hashing to
+    /// [`ELReader::calculate_operator_avs_registration_digest_hash`]
+    async fn operator_avs_registration_typed_data(
+        &self,
+        operator: Address,
+        avs: Address,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<TypedData, ElContractsError>;
+
+    /// Shares of `operator_addr` in `strategy_addr`
+    async fn get_operator_shares_in_strategy(
+        &self,
+        operator_addr: Address,
+        strategy_addr: Address,
+    ) -> Result<U256, ElContractsError>;
+
+    /// Whether `operator_addr` is frozen by the slasher
+    async fn operator_is_frozen(&self, operator_addr: Address) -> Result<bool, ElContractsError>;
+
+    /// Block until which `service_manager_addr` can slash `operator_addr`
+    async fn service_manager_can_slash_operator_until_block(
+        &self,
+        operator_addr: Address,
+        service_manager_addr: Address,
+    ) -> Result<u32, ElContractsError>;
+
+    /// Strategy contract, underlying token contract and underlying token of `strategy_addr`
+    async fn get_strategy_and_underlying_erc20_token(
+        &self,
+        strategy_addr: Address,
+    ) -> Result<(Address, Address, Address), ElContractsError>;
+
+    /// Details of `operator`
+    async fn get_operator_details(&self, operator: Address) -> Result<Operator, ElContractsError>;
+
+    /// Whether `operator` is registered in EigenLayer
+    async fn is_operator_registered(&self, operator: Address) -> Result<bool, ElContractsError>;
+}
+
 impl ELChainReader {
     pub fn new(
         slasher: Address,
@@ -28,19 +108,37 @@ impl ELChainReader {
         }
     }
 
+    /// Builds the reader, resolving the slasher from the delegation manager.
+    ///
+    /// If `expected_chain_id` is set, the provider must report that chain id. Every
+    /// given and resolved contract address is checked to have code deployed.
     pub async fn build(
         delegation_manager: Address,
         avs_directory: Address,
         client: &String,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+        expected_chain_id: Option<u64>,
+    ) -> Result<Self, ElContractsError> {
+        verify_chain_and_contracts(
+            client,
+            expected_chain_id,
+            &[delegation_manager, avs_directory],
+        )
+        .await?;
+
         let provider = get_provider(client);
 
         let contract_delegation_manager = DelegationManager::new(delegation_manager, provider);
 
-        let slasher = contract_delegation_manager.slasher().call().await?;
+        let slasher = contract_delegation_manager
+            .slasher()
+            .call()
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::GetSlasher))?;
 
         let DelegationManager::slasherReturn { _0: slasher_addr } = slasher;
 
+        verify_chain_and_contracts(client, None, &[slasher_addr]).await?;
+
         Ok(Self {
             avs_directory,
             slasher: slasher_addr,
@@ -48,15 +146,18 @@ impl ELChainReader {
             provider: client.to_string(),
         })
     }
+}
 
-    pub async fn calculate_delegation_approval_digest_hash(
+#[async_trait]
+impl ELReader for ELChainReader {
+    async fn calculate_delegation_approval_digest_hash(
         &self,
         staker: Address,
         operator: Address,
         delegation_approver: Address,
         approve_salt: FixedBytes<32>,
         expiry: U256,
-    ) -> Result<FixedBytes<32>, Box<dyn std::error::Error>> {
+    ) -> Result<FixedBytes<32>, ElContractsError> {
         let provider = get_provider(&self.provider);
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
         let delegation_approval_digest_hash = contract_delegation_manager
@@ -68,20 +169,23 @@ impl ELChainReader {
                 expiry,
             )
             .call()
-            .await?;
+            .await
+            .map_err(|e| {
+                ElContractsError::from_call(e, ElContractsError::GetDelegationApprovalDigestHash)
+            })?;
         let DelegationManager::calculateDelegationApprovalDigestHashReturn { _0: digest_hash } =
             delegation_approval_digest_hash;
 
         Ok(digest_hash)
     }
 
-    pub async fn calculate_operator_avs_registration_digest_hash(
+    async fn calculate_operator_avs_registration_digest_hash(
         &self,
         operator: Address,
         avs: Address,
         salt: FixedBytes<32>,
         expiry: U256,
-    ) -> Result<FixedBytes<32>, Box<dyn std::error::Error>> {
+    ) -> Result<FixedBytes<32>, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_avs_directory = AVSDirectory::new(self.avs_directory, provider);
@@ -89,7 +193,13 @@ impl ELChainReader {
         let operator_avs_registration_digest_hash = contract_avs_directory
             .calculateOperatorAVSRegistrationDigestHash(operator, avs, salt, expiry)
             .call()
-            .await?;
+            .await
+            .map_err(|e| {
+                ElContractsError::from_call(
+                    e,
+                    ElContractsError::GetOperatorAvsRegistrationDigestHash,
+                )
+            })?;
 
         let AVSDirectory::calculateOperatorAVSRegistrationDigestHashReturn { _0: avs_hash } =
             operator_avs_registration_digest_hash;
@@ -97,11 +207,57 @@ impl ELChainReader {
         Ok(avs_hash)
     }
 
-    pub async fn get_operator_shares_in_strategy(
+    async fn delegation_approval_typed_data(
+        &self,
+        staker: Address,
+        operator: Address,
+        delegation_approver: Address,
+        approve_salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<TypedData, ElContractsError> {
+        let chain_id = get_provider(&self.provider)
+            .get_chain_id()
+            .await
+            .map_err(ElContractsError::GetChainId)?;
+
+        Ok(delegation_approval_typed_data(
+            chain_id,
+            self.delegation_manager,
+            delegation_approver,
+            staker,
+            operator,
+            approve_salt,
+            expiry,
+        ))
+    }
+
+    async fn operator_avs_registration_typed_data(
+        &self,
+        operator: Address,
+        avs: Address,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<TypedData, ElContractsError> {
+        let chain_id = get_provider(&self.provider)
+            .get_chain_id()
+            .await
+            .map_err(ElContractsError::GetChainId)?;
+
+        Ok(operator_avs_registration_typed_data(
+            chain_id,
+            self.avs_directory,
+            operator,
+            avs,
+            salt,
+            expiry,
+        ))
+    }
+
+    async fn get_operator_shares_in_strategy(
         &self,
         operator_addr: Address,
         strategy_addr: Address,
-    ) -> Result<U256, Box<dyn std::error::Error>> {
+    ) -> Result<U256, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
@@ -109,31 +265,33 @@ impl ELChainReader {
         let operator_shares_in_strategy = contract_delegation_manager
             .operatorShares(operator_addr, strategy_addr)
             .call()
-            .await?;
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::GetOperatorShares))?;
         let DelegationManager::operatorSharesReturn { _0: shares } = operator_shares_in_strategy;
 
         Ok(shares)
     }
 
-    pub async fn operator_is_frozen(
-        &self,
-        operator_addr: Address,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    async fn operator_is_frozen(&self, operator_addr: Address) -> Result<bool, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_slasher = ISlasher::new(self.slasher, provider);
 
-        let operator_is_frozen = contract_slasher.isFrozen(operator_addr).call().await?;
+        let operator_is_frozen = contract_slasher
+            .isFrozen(operator_addr)
+            .call()
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::IsFrozen))?;
 
         let ISlasher::isFrozenReturn { _0: is_froze } = operator_is_frozen;
         Ok(is_froze)
     }
 
-    pub async fn service_manager_can_slash_operator_until_block(
+    async fn service_manager_can_slash_operator_until_block(
         &self,
         operator_addr: Address,
         service_manager_addr: Address,
-    ) -> Result<u32, Box<dyn std::error::Error>> {
+    ) -> Result<u32, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_slasher = ISlasher::new(self.slasher, provider);
@@ -141,7 +299,13 @@ impl ELChainReader {
         let service_manager_can_slash_operator_until_block = contract_slasher
             .contractCanSlashOperatorUntilBlock(operator_addr, service_manager_addr)
             .call()
-            .await?;
+            .await
+            .map_err(|e| {
+                ElContractsError::from_call(
+                    e,
+                    ElContractsError::ServiceManagerCanSlashOperatorExpiry,
+                )
+            })?;
 
         let ISlasher::contractCanSlashOperatorUntilBlockReturn { _0: can_slash } =
             service_manager_can_slash_operator_until_block;
@@ -149,15 +313,19 @@ impl ELChainReader {
         Ok(can_slash)
     }
 
-    pub async fn get_strategy_and_underlying_erc20_token(
+    async fn get_strategy_and_underlying_erc20_token(
         &self,
         strategy_addr: Address,
-    ) -> Result<(Address, Address, Address), Box<dyn std::error::Error>> {
+    ) -> Result<(Address, Address, Address), ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_strategy = IStrategy::new(strategy_addr, &provider);
 
-        let underlying_token = contract_strategy.underlyingToken().call().await?;
+        let underlying_token = contract_strategy
+            .underlyingToken()
+            .call()
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::GetUnderlyingToken))?;
 
         let IStrategy::underlyingTokenReturn {
             _0: underlying_token_addr,
@@ -165,17 +333,14 @@ impl ELChainReader {
 
         let contract_ierc20 = IERC20::new(underlying_token_addr, &provider);
 
-        return Ok((
+        Ok((
             strategy_addr,
             underlying_token_addr,
             *contract_ierc20.address(),
-        ));
+        ))
     }
 
-    pub async fn get_operator_details(
-        &self,
-        operator: Address,
-    ) -> Result<Operator, Box<dyn std::error::Error>> {
+    async fn get_operator_details(&self, operator: Address) -> Result<Operator, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_delegation_manager =
@@ -184,7 +349,8 @@ impl ELChainReader {
         let operator_det = contract_delegation_manager
             .operatorDetails(operator)
             .call()
-            .await?;
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::GetOperatorDetails))?;
 
         let DelegationManager::operatorDetailsReturn {
             _0: operator_details,
@@ -199,10 +365,7 @@ impl ELChainReader {
         ))
     }
 
-    pub async fn is_operator_registered(
-        &self,
-        operator: Address,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    async fn is_operator_registered(&self, operator: Address) -> Result<bool, ElContractsError> {
         let provider = get_provider(&self.provider);
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
@@ -210,7 +373,8 @@ impl ELChainReader {
         let is_operator = contract_delegation_manager
             .isOperator(operator)
             .call()
-            .await?;
+            .await
+            .map_err(|e| ElContractsError::from_call(e, ElContractsError::IsOperator))?;
 
         let DelegationManager::isOperatorReturn { _0: is_operator_is } = is_operator;
         Ok(is_operator_is)
@@ -325,6 +489,49 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_build_el_chain_reader() {
+        let delegation_manager_address = anvil_constants::get_delegation_manager_address().await;
+        let el_chain_reader = build_el_chain_reader().await;
+
+        let built = ELChainReader::build(
+            delegation_manager_address,
+            el_chain_reader.avs_directory,
+            &"http://localhost:8545".to_string(),
+            Some(31337),
+        )
+        .await
+        .unwrap();
+        assert_eq!(built.slasher, el_chain_reader.slasher);
+
+        let wrong_chain = ELChainReader::build(
+            delegation_manager_address,
+            el_chain_reader.avs_directory,
+            &"http://localhost:8545".to_string(),
+            Some(1),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            wrong_chain.to_string(),
+            "chain id mismatch: expected 1, provider returned 31337"
+        );
+
+        let no_code_address = address!("00000000000000000000000000000000000000aa");
+        let no_code = ELChainReader::build(
+            no_code_address,
+            el_chain_reader.avs_directory,
+            &"http://localhost:8545".to_string(),
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            no_code.to_string(),
+            format!("No contract code found at address {no_code_address}")
+        );
+    }
+
     #[tokio::test]
     async fn test_calculate_operator_avs_registration_digest_hash() {
         let el_chain_reader = build_el_chain_reader().await;
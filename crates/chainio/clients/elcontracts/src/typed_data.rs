@@ -0,0 +1,189 @@
+//! EIP-712 typed data of the signatures checked by the EigenLayer core contracts.
+//!
+//! The signing hash of these typed data equals the digest returned by
+//! `AVSDirectory.calculateOperatorAVSRegistrationDigestHash` and
+//! `DelegationManager.calculateDelegationApprovalDigestHash`, so that signers only
+//! handling typed data (e.g. custodians) can produce the signatures.
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, FixedBytes, U256};
+use serde_json::json;
+
+/// Domain name of the EigenLayer core contracts
+const DOMAIN_NAME: &str = "EigenLayer";
+
+fn eigenlayer_typed_data(
+    chain_id: u64,
+    verifying_contract: Address,
+    primary_type: &str,
+    fields: serde_json::Value,
+    message: serde_json::Value,
+) -> TypedData {
+    serde_json::from_value(json!({
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"},
+            ],
+            primary_type: fields,
+        },
+        "primaryType": primary_type,
+        "domain": {
+            "name": DOMAIN_NAME,
+            "chainId": chain_id,
+            "verifyingContract": verifying_contract,
+        },
+        "message": message,
+    }))
+    .expect("valid EigenLayer typed data")
+}
+
+/// Typed data the operator signs to register to `avs` in the AVSDirectory
+pub fn operator_avs_registration_typed_data(
+    chain_id: u64,
+    avs_directory: Address,
+    operator: Address,
+    avs: Address,
+    salt: FixedBytes<32>,
+    expiry: U256,
+) -> TypedData {
+    eigenlayer_typed_data(
+        chain_id,
+        avs_directory,
+        "OperatorAVSRegistration",
+        json!([
+            {"name": "operator", "type": "address"},
+            {"name": "avs", "type": "address"},
+            {"name": "salt", "type": "bytes32"},
+            {"name": "expiry", "type": "uint256"},
+        ]),
+        json!({
+            "operator": operator,
+            "avs": avs,
+            "salt": salt,
+            "expiry": expiry,
+        }),
+    )
+}
+
+/// Typed data the delegation approver of `operator` signs to let `staker` delegate
+pub fn delegation_approval_typed_data(
+    chain_id: u64,
+    delegation_manager: Address,
+    delegation_approver: Address,
+    staker: Address,
+    operator: Address,
+    salt: FixedBytes<32>,
+    expiry: U256,
+) -> TypedData {
+    eigenlayer_typed_data(
+        chain_id,
+        delegation_manager,
+        "DelegationApproval",
+        json!([
+            {"name": "delegationApprover", "type": "address"},
+            {"name": "staker", "type": "address"},
+            {"name": "operator", "type": "address"},
+            {"name": "salt", "type": "bytes32"},
+            {"name": "expiry", "type": "uint256"},
+        ]),
+        json!({
+            "delegationApprover": delegation_approver,
+            "staker": staker,
+            "operator": operator,
+            "salt": salt,
+            "expiry": expiry,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, keccak256, B256};
+    use alloy_sol_types::SolValue;
+
+    const AVS_DIRECTORY: Address = address!("055733000064333CaDDbC92763c58BF0192fFeBf");
+    const DELEGATION_MANAGER: Address = address!("A44151489861Fe9e3055d95adC98FbD462B948e7");
+    const OPERATOR: Address = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+    const STAKER: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+    const AVS: Address = address!("53012C69A189cfA2D9d29eb6F19B32e0A2EA3490");
+
+    /// Digest as computed by the EigenLayer contracts
+    fn contract_digest(verifying_contract: Address, struct_hash: B256) -> B256 {
+        let domain_separator = keccak256(
+            (
+                keccak256("EIP712Domain(string name,uint256 chainId,address verifyingContract)"),
+                keccak256("EigenLayer"),
+                U256::from(17000),
+                verifying_contract,
+            )
+                .abi_encode(),
+        );
+        keccak256(
+            [
+                &[0x19, 0x01],
+                domain_separator.as_slice(),
+                struct_hash.as_slice(),
+            ]
+            .concat(),
+        )
+    }
+
+    #[test]
+    fn test_operator_avs_registration_typed_data() {
+        let salt = FixedBytes::from([0x02; 32]);
+        let expiry = U256::from(1_000_000_000);
+        let typed_data =
+            operator_avs_registration_typed_data(17000, AVS_DIRECTORY, OPERATOR, AVS, salt, expiry);
+
+        let struct_hash = keccak256(
+            (
+                keccak256(
+                    "OperatorAVSRegistration(address operator,address avs,bytes32 salt,uint256 expiry)",
+                ),
+                OPERATOR,
+                AVS,
+                salt,
+                expiry,
+            )
+                .abi_encode(),
+        );
+        assert_eq!(
+            typed_data.eip712_signing_hash().unwrap(),
+            contract_digest(AVS_DIRECTORY, struct_hash)
+        );
+    }
+
+    #[test]
+    fn test_delegation_approval_typed_data() {
+        let approver = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let salt = FixedBytes::from([0x03; 32]);
+        let expiry = U256::from(2_000_000_000);
+        let typed_data = delegation_approval_typed_data(
+            17000,
+            DELEGATION_MANAGER,
+            approver,
+            STAKER,
+            OPERATOR,
+            salt,
+            expiry,
+        );
+
+        let struct_hash = keccak256(
+            (
+                keccak256("DelegationApproval(address delegationApprover,address staker,address operator,bytes32 salt,uint256 expiry)"),
+                approver,
+                STAKER,
+                OPERATOR,
+                salt,
+                expiry,
+            )
+                .abi_encode(),
+        );
+        assert_eq!(
+            typed_data.eip712_signing_hash().unwrap(),
+            contract_digest(DELEGATION_MANAGER, struct_hash)
+        );
+    }
+}
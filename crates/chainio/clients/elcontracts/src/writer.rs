@@ -1,17 +1,29 @@
-use crate::reader::ELChainReader;
+use crate::error::ElContractsError;
+use crate::reader::{ELChainReader, ELReader};
 use alloy_primitives::FixedBytes;
-use alloy_primitives::{Address, TxHash, U256};
+use alloy_primitives::{Address, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_sol_types::SolCall;
+use async_trait::async_trait;
+use eigen_client_eth::outcome::{send_with_outcome, TxOutcome};
+use eigen_client_eth::simulation::{simulate, TxSimulation};
+use eigen_client_eth::wallet::{Eip712Signer, PrivateKeyWallet, TxSender};
 pub use eigen_types::operator::Operator;
 use eigen_utils::{
     binding::{
         DelegationManager::{self},
         StrategyManager, IERC20,
     },
-    get_signer,
+    get_provider,
 };
+use std::fmt::Debug;
+use std::sync::Arc;
 
 use tracing::info;
-use DelegationManager::OperatorDetails;
+use DelegationManager::{OperatorDetails, SignatureWithExpiry};
+
+/// Simulation of a transaction calling `C`, with its revert mapped to an error variant
+pub type Simulation<C> = TxSimulation<<C as SolCall>::Return, ElContractsError>;
 
 #[derive(Debug, Clone)]
 pub struct ELChainWriter {
@@ -19,7 +31,41 @@ pub struct ELChainWriter {
     strategy_manager: Address,
     el_chain_reader: ELChainReader,
     provider: String,
-    signer: String,
+    tx_sender: Arc<dyn TxSender>,
+    eip712_signer: Arc<dyn Eip712Signer>,
+}
+
+/// Write access to the EigenLayer core contracts, implemented by [`ELChainWriter`].
+#[async_trait]
+pub trait ELWriter: Debug + Send + Sync {
+    /// Register `operator` in EigenLayer
+    async fn register_as_operator(&self, operator: Operator)
+        -> Result<TxOutcome, ElContractsError>;
+
+    /// Update the details and then the metadata uri of `operator`, returning the
+    /// outcome of both transactions
+    async fn update_operator_details(
+        &self,
+        operator: Operator,
+    ) -> Result<(TxOutcome, TxOutcome), ElContractsError>;
+
+    /// Sign, as delegation approver of `operator`, the approval for `staker` to delegate
+    /// to it. The signature is checked against the digest computed by the
+    /// DelegationManager before being returned.
+    async fn sign_delegation_approval(
+        &self,
+        staker: Address,
+        operator: Address,
+        approve_salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<SignatureWithExpiry, ElContractsError>;
+
+    /// Approve and deposit `amount` of the underlying token into `strategy_addr`
+    async fn deposit_erc20_into_strategy(
+        &self,
+        strategy_addr: Address,
+        amount: U256,
+    ) -> Result<TxOutcome, ElContractsError>;
 }
 
 impl ELChainWriter {
@@ -30,126 +76,310 @@ impl ELChainWriter {
         provider: String,
         signer: String,
     ) -> Self {
+        let wallet = Arc::new(PrivateKeyWallet::new(signer, provider.clone()));
         Self {
             delegation_manager,
             strategy_manager,
             el_chain_reader,
             provider,
-            signer,
+            tx_sender: wallet.clone(),
+            eip712_signer: wallet,
         }
     }
 
-    pub async fn register_as_operator(
+    /// Sends the transactions through `tx_sender` instead of signing them with the
+    /// private key given to [`ELChainWriter::new`].
+    pub fn with_tx_sender(mut self, tx_sender: Arc<dyn TxSender>) -> Self {
+        self.tx_sender = tx_sender;
+        self
+    }
+
+    /// Signs the EIP-712 messages with `eip712_signer` instead of the private key given
+    /// to [`ELChainWriter::new`].
+    pub fn with_eip712_signer(mut self, eip712_signer: Arc<dyn Eip712Signer>) -> Self {
+        self.eip712_signer = eip712_signer;
+        self
+    }
+
+    /// Simulates `tx`, a call to `C`, from the account sending the transactions, mapping
+    /// a revert to its revert reason variant, or to [`ElContractsError::CustomError`] with the
+    /// data of a custom error.
+    async fn simulate<C: SolCall>(
         &self,
-        operator: Operator,
-    ) -> Result<FixedBytes<32>, Box<dyn std::error::Error>> {
-        info!(
-            "registering operator {:?} to EigenLayer",
-            operator.has_address()
-        );
+        tx: TransactionRequest,
+    ) -> Result<Simulation<C>, ElContractsError> {
+        let tx = tx.from(self.tx_sender.sender_address().await?);
+        let simulation = simulate::<C>(&self.provider, &tx)
+            .await
+            .map_err(ElContractsError::Simulate)?;
+        Ok(simulation.map_err(ElContractsError::from_simulated_revert))
+    }
+
+    fn register_as_operator_tx(&self, operator: &Operator) -> TransactionRequest {
         let op_details = OperatorDetails {
             earningsReceiver: operator.has_earnings_receiver_address(),
             delegationApprover: operator.has_delegation_approver_address(),
             stakerOptOutWindowBlocks: operator.has_staker_opt_out_window_blocks(),
         };
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = get_provider(&self.provider);
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
-        let binding = match operator.has_metadata_url() {
-            Some(metadata) => {
-                let contract_call =
-                    contract_delegation_manager.registerAsOperator(op_details, metadata);
-                contract_call.gas(130000)
-            }
-            None => {
-                let contract_call =
-                    contract_delegation_manager.registerAsOperator(op_details, "".to_string());
-                contract_call.gas(130000)
-            }
-        };
-        let tx = binding.send().await?;
-        let receipt = tx.get_receipt().await?;
-        let tx_status = receipt.status();
-        let hash = receipt.transaction_hash;
-        match tx_status {
-            true => {
-                info!(tx_hash = %receipt.transaction_hash, "tx successfully included");
-                Ok(hash)
-            }
-            false => {
-                info!(tx_hash = %receipt.transaction_hash, "tx failed");
-                Err("Failed to register operator".into())
-            }
-        }
+        let contract_call = contract_delegation_manager
+            .registerAsOperator(op_details, operator.has_metadata_url().unwrap_or_default());
+        contract_call.gas(130000).as_ref().clone()
     }
 
-    pub async fn update_operator_details(
+    /// Simulates [`ELWriter::register_as_operator`] at the pending block, without
+    /// signing or sending the transaction
+    pub async fn simulate_register_as_operator(
         &self,
         operator: Operator,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
-        info!(
-            "updating operator detils of operator {:?} to EigenLayer",
-            operator.has_address()
-        );
+    ) -> Result<Simulation<DelegationManager::registerAsOperatorCall>, ElContractsError> {
+        self.simulate::<DelegationManager::registerAsOperatorCall>(
+            self.register_as_operator_tx(&operator),
+        )
+        .await
+    }
+
+    /// Transactions setting the details and the metadata uri of `operator`
+    fn update_operator_details_txs(
+        &self,
+        operator: &Operator,
+    ) -> (TransactionRequest, TransactionRequest) {
         let operator_details = OperatorDetails {
             earningsReceiver: operator.has_earnings_receiver_address(),
             delegationApprover: operator.has_delegation_approver_address(),
             stakerOptOutWindowBlocks: operator.has_staker_opt_out_window_blocks(),
         };
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = get_provider(&self.provider);
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
         let contract_call_modify_operator_details =
             contract_delegation_manager.modifyOperatorDetails(operator_details);
-
-        let tx = contract_call_modify_operator_details.send().await?;
-
-        info!(tx_hash = %tx.tx_hash(), operator = %operator.has_address(), "succesfully updated operator details");
-
         let contract_call_update_metadata_uri = contract_delegation_manager
             .updateOperatorMetadataURI(operator.has_metadata_url().unwrap_or_default());
 
-        let metadata_tx = contract_call_update_metadata_uri.send().await?;
+        (
+            contract_call_modify_operator_details.as_ref().clone(),
+            contract_call_update_metadata_uri.as_ref().clone(),
+        )
+    }
+
+    /// Simulates the two transactions of [`ELWriter::update_operator_details`],
+    /// setting the details and then the metadata uri, at the pending block
+    pub async fn simulate_update_operator_details(
+        &self,
+        operator: Operator,
+    ) -> Result<
+        (
+            Simulation<DelegationManager::modifyOperatorDetailsCall>,
+            Simulation<DelegationManager::updateOperatorMetadataURICall>,
+        ),
+        ElContractsError,
+    > {
+        let (modify_operator_details_tx, update_metadata_uri_tx) =
+            self.update_operator_details_txs(&operator);
 
-        Ok(*metadata_tx.tx_hash())
+        Ok((
+            self.simulate::<DelegationManager::modifyOperatorDetailsCall>(
+                modify_operator_details_tx,
+            )
+            .await?,
+            self.simulate::<DelegationManager::updateOperatorMetadataURICall>(
+                update_metadata_uri_tx,
+            )
+            .await?,
+        ))
     }
 
-    pub async fn deposit_erc20_into_strategy(
+    /// Transactions approving the strategy manager to spend `amount` of the underlying
+    /// token of `strategy_addr`, and depositing it
+    async fn deposit_erc20_into_strategy_txs(
         &self,
         strategy_addr: Address,
         amount: U256,
-    ) -> Result<TxHash, Box<dyn std::error::Error>> {
-        info!(
-            "depositing {:?} tokens into strategy {:?}",
-            amount, strategy_addr
-        );
+    ) -> Result<(TransactionRequest, TransactionRequest), ElContractsError> {
         let tokens = self
             .el_chain_reader
             .get_strategy_and_underlying_erc20_token(strategy_addr)
             .await?;
         let (_, underlying_token_contract, underlying_token) = tokens;
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = get_provider(&self.provider);
 
         let contract_underlying_token = IERC20::new(underlying_token_contract, &provider);
 
         let contract_call = contract_underlying_token.approve(self.strategy_manager, amount);
 
-        let _approve = contract_call.send().await?;
-
         let contract_strategy_manager = StrategyManager::new(self.strategy_manager, &provider);
 
         let deposit_contract_call =
             contract_strategy_manager.depositIntoStrategy(strategy_addr, underlying_token, amount);
 
-        let tx = deposit_contract_call.send().await?;
+        Ok((
+            contract_call.as_ref().clone(),
+            deposit_contract_call.as_ref().clone(),
+        ))
+    }
+
+    /// Simulates the two transactions of
+    /// [`ELWriter::deposit_erc20_into_strategy`] at the pending block.
+    ///
+    /// Both run against the current state: the deposit reverts with an insufficient
+    /// allowance unless the strategy manager is already approved for `amount`.
+    pub async fn simulate_deposit_erc20_into_strategy(
+        &self,
+        strategy_addr: Address,
+        amount: U256,
+    ) -> Result<
+        (
+            Simulation<IERC20::approveCall>,
+            Simulation<StrategyManager::depositIntoStrategyCall>,
+        ),
+        ElContractsError,
+    > {
+        let (approve_tx, deposit_tx) = self
+            .deposit_erc20_into_strategy_txs(strategy_addr, amount)
+            .await?;
+
+        Ok((
+            self.simulate::<IERC20::approveCall>(approve_tx).await?,
+            self.simulate::<StrategyManager::depositIntoStrategyCall>(deposit_tx)
+                .await?,
+        ))
+    }
+}
+
+#[async_trait]
+impl ELWriter for ELChainWriter {
+    async fn register_as_operator(
+        &self,
+        operator: Operator,
+    ) -> Result<TxOutcome, ElContractsError> {
+        info!(
+            "registering operator {:?} to EigenLayer",
+            operator.has_address()
+        );
+        let tx = self.register_as_operator_tx(&operator);
+        let outcome = send_with_outcome(self.tx_sender.as_ref(), &self.provider, &tx, |e| {
+            ElContractsError::from_call(e, ElContractsError::RegisterAsOperator)
+        })
+        .await?;
+
+        info!(tx_hash = %outcome.tx_hash, "tx successfully included");
+        Ok(outcome)
+    }
+
+    async fn update_operator_details(
+        &self,
+        operator: Operator,
+    ) -> Result<(TxOutcome, TxOutcome), ElContractsError> {
+        info!(
+            "updating operator detils of operator {:?} to EigenLayer",
+            operator.has_address()
+        );
+        let (modify_operator_details_tx, update_metadata_uri_tx) =
+            self.update_operator_details_txs(&operator);
+
+        let details_outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            &modify_operator_details_tx,
+            |e| ElContractsError::from_call(e, ElContractsError::ModifyOperatorDetails),
+        )
+        .await?;
+
+        info!(tx_hash = %details_outcome.tx_hash, operator = %operator.has_address(), "succesfully updated operator details");
+
+        let metadata_outcome = send_with_outcome(
+            self.tx_sender.as_ref(),
+            &self.provider,
+            &update_metadata_uri_tx,
+            |e| ElContractsError::from_call(e, ElContractsError::UpdateMetadataUri),
+        )
+        .await?;
+
+        Ok((details_outcome, metadata_outcome))
+    }
+
+    async fn sign_delegation_approval(
+        &self,
+        staker: Address,
+        operator: Address,
+        approve_salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<SignatureWithExpiry, ElContractsError> {
+        let delegation_approver = self.eip712_signer.signer_address().await?;
+        let typed_data = self
+            .el_chain_reader
+            .delegation_approval_typed_data(
+                staker,
+                operator,
+                delegation_approver,
+                approve_salt,
+                expiry,
+            )
+            .await?;
+        let digest = self
+            .el_chain_reader
+            .calculate_delegation_approval_digest_hash(
+                staker,
+                operator,
+                delegation_approver,
+                approve_salt,
+                expiry,
+            )
+            .await?;
+        let typed_data_hash = typed_data
+            .eip712_signing_hash()
+            .map_err(ElContractsError::TypedDataHash)?;
+        if typed_data_hash != digest {
+            return Err(ElContractsError::TypedDataDigestMismatch {
+                typed_data_hash,
+                digest,
+            });
+        }
+
+        let signature = self.eip712_signer.sign_typed_data(&typed_data).await?;
+        info!(staker = %staker, operator = %operator, delegation_approver = %delegation_approver, "signed delegation approval");
+
+        Ok(SignatureWithExpiry {
+            signature: signature.as_bytes().into(),
+            expiry,
+        })
+    }
+
+    async fn deposit_erc20_into_strategy(
+        &self,
+        strategy_addr: Address,
+        amount: U256,
+    ) -> Result<TxOutcome, ElContractsError> {
+        info!(
+            "depositing {:?} tokens into strategy {:?}",
+            amount, strategy_addr
+        );
+        let (approve_tx, deposit_tx) = self
+            .deposit_erc20_into_strategy_txs(strategy_addr, amount)
+            .await?;
+
+        let _approve =
+            send_with_outcome(self.tx_sender.as_ref(), &self.provider, &approve_tx, |e| {
+                ElContractsError::from_call(e, ElContractsError::ApproveCallToUnderlyingToken)
+            })
+            .await?;
+
+        let outcome =
+            send_with_outcome(self.tx_sender.as_ref(), &self.provider, &deposit_tx, |e| {
+                ElContractsError::from_call(e, ElContractsError::DepositIntoStrategy)
+            })
+            .await?;
 
         info!(
             "deposited {:?} tokens into strategy {:?}",
             amount, strategy_addr
         );
-        Ok(*tx.tx_hash())
+        Ok(outcome)
     }
 }
 
@@ -226,24 +456,42 @@ mod tests {
 
         // operator who registered at index 1
         let operator_address = operator.address();
-        let operator_details = Operator::new(
-            operator_address,
-            operator_address,
-            Address::ZERO,
-            "0".parse().unwrap(),
-            Some("https://coolstuff.com/operator/".to_string()),
-        );
+        let operator_details = || {
+            Operator::new(
+                operator_address,
+                operator_address,
+                Address::ZERO,
+                "0".parse().unwrap(),
+                Some("https://coolstuff.com/operator/".to_string()),
+            )
+        };
         assert!(el_chain_reader
             .is_operator_registered(operator_address)
             .await
             .unwrap());
+        let simulation = el_chain_writer
+            .simulate_register_as_operator(operator_details())
+            .await
+            .unwrap();
         assert_eq!(
-            el_chain_writer
-                .register_as_operator(operator_details)
-                .await
-                .unwrap_err()
-                .to_string(),
-            "Failed to register operator"
+            simulation.calldata[..4],
+            DelegationManager::registerAsOperatorCall::SELECTOR
         );
+        assert_eq!(simulation.gas_estimate, None);
+        assert!(matches!(
+            simulation.result,
+            Err(ElContractsError::OperatorAlreadyRegistered)
+        ));
+
+        let err = el_chain_writer
+            .register_as_operator(operator_details())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ElContractsError::TransactionReverted(reverted)
+                if reverted.reason.as_deref()
+                    == Some("DelegationManager.registerAsOperator: operator has already registered")
+        ));
     }
 }
@@ -0,0 +1,71 @@
+//! Checks that a provider serves the chain and contracts a client is built for.
+use alloy_primitives::Address;
+use alloy_provider::Provider;
+use alloy_transport::TransportError;
+use eigen_utils::get_provider;
+use thiserror::Error;
+
+/// Provider is not connected to the expected chain or contracts
+#[derive(Debug, Error)]
+pub enum ChainError {
+    /// Failed to get chain id
+    #[error("Failed to get chain id")]
+    GetChainId(#[source] TransportError),
+
+    /// Provider is connected to a different chain than expected
+    #[error("chain id mismatch: expected {expected}, provider returned {actual}")]
+    ChainIdMismatch {
+        /// Chain id the client was built for
+        expected: u64,
+        /// Chain id reported by the provider
+        actual: u64,
+    },
+
+    /// Failed to get code at address
+    #[error("Failed to get code at address {address}")]
+    GetCode {
+        /// Address of the contract
+        address: Address,
+        /// Error of the provider
+        #[source]
+        source: TransportError,
+    },
+
+    /// No contract deployed at address
+    #[error("No contract code found at address {0}")]
+    NoContractCode(Address),
+}
+
+/// Checks that the provider at `provider_url` is connected to `expected_chain_id`
+/// (when set) and that every address in `addresses` has contract code deployed.
+pub async fn verify_chain_and_contracts(
+    provider_url: &str,
+    expected_chain_id: Option<u64>,
+    addresses: &[Address],
+) -> Result<(), ChainError> {
+    let provider = get_provider(provider_url);
+
+    if let Some(expected) = expected_chain_id {
+        let actual = provider
+            .get_chain_id()
+            .await
+            .map_err(ChainError::GetChainId)?;
+        if actual != expected {
+            return Err(ChainError::ChainIdMismatch { expected, actual });
+        }
+    }
+
+    for address in addresses {
+        let code = provider
+            .get_code_at(*address)
+            .await
+            .map_err(|source| ChainError::GetCode {
+                address: *address,
+                source,
+            })?;
+        if code.is_empty() {
+            return Err(ChainError::NoContractCode(*address));
+        }
+    }
+    Ok(())
+}
@@ -3,5 +3,9 @@
     issue_tracker_base_url = "https://github.com/supernovahs/eigen-rs/issues/"
 )]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
+pub mod chain;
 pub mod client;
 mod instrumented_client;
+pub mod outcome;
+pub mod simulation;
+pub mod wallet;
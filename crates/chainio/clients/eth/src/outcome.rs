@@ -0,0 +1,374 @@
+//! Outcome of mined transactions: receipt, gas and decoded EigenLayer events, or the
+//! revert reason of the transactions that failed.
+use crate::simulation::SimulatedRevert;
+use crate::wallet::{TxSender, WalletError};
+use alloy_primitives::{Bytes, Log, TxHash};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, TransactionReceipt, TransactionRequest};
+use alloy_sol_types::{SolEventInterface, SolInterface};
+use eigen_utils::binding::{
+    AVSDirectory, BLSApkRegistry, DelegationManager, RegistryCoordinator, StakeRegistry,
+    StrategyManager, IERC20,
+};
+use eigen_utils::{get_provider, revert::revert_data};
+use std::fmt;
+use std::time::Duration;
+
+/// Reason of the transactions that revert after using all their gas
+const OUT_OF_GAS: &str = "out of gas";
+
+/// Interval between two receipt polls of a transaction sent by [`send_with_outcome`]
+pub const RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Time a transaction sent by [`send_with_outcome`] may take to be mined
+pub const RECEIPT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Event emitted by an EigenLayer core or middleware contract
+///
+/// Events declared by several contracts, e.g. `OwnershipTransferred`, are decoded as
+/// those of the first contract below declaring them; the address of the log tells the
+/// actual emitter.
+#[derive(Debug)]
+pub enum EigenLayerEvent {
+    /// Event of the DelegationManager, e.g. `OperatorRegistered`
+    DelegationManager(DelegationManager::DelegationManagerEvents),
+    /// Event of the StrategyManager, e.g. `Deposit`
+    StrategyManager(StrategyManager::StrategyManagerEvents),
+    /// Event of the AVSDirectory, e.g. `OperatorAVSRegistrationStatusUpdated`
+    AvsDirectory(AVSDirectory::AVSDirectoryEvents),
+    /// Event of the RegistryCoordinator, e.g. `OperatorSocketUpdate`
+    RegistryCoordinator(RegistryCoordinator::RegistryCoordinatorEvents),
+    /// Event of the StakeRegistry, e.g. `OperatorStakeUpdate`
+    StakeRegistry(StakeRegistry::StakeRegistryEvents),
+    /// Event of the BLSApkRegistry, e.g. `NewPubkeyRegistration`
+    BlsApkRegistry(BLSApkRegistry::BLSApkRegistryEvents),
+    /// Event of an ERC20 token, e.g. the `Approval` before a deposit
+    Erc20(IERC20::IERC20Events),
+}
+
+impl EigenLayerEvent {
+    /// Event of `log`, `None` if no EigenLayer contract declares it
+    pub fn decode(log: &Log) -> Option<Log<Self>> {
+        let decode = |log: &Log| -> Option<Self> {
+            let (topics, data) = (log.topics(), &log.data.data[..]);
+            let event = if let Ok(event) =
+                DelegationManager::DelegationManagerEvents::decode_raw_log(topics, data, true)
+            {
+                Self::DelegationManager(event)
+            } else if let Ok(event) =
+                StrategyManager::StrategyManagerEvents::decode_raw_log(topics, data, true)
+            {
+                Self::StrategyManager(event)
+            } else if let Ok(event) =
+                AVSDirectory::AVSDirectoryEvents::decode_raw_log(topics, data, true)
+            {
+                Self::AvsDirectory(event)
+            } else if let Ok(event) =
+                RegistryCoordinator::RegistryCoordinatorEvents::decode_raw_log(topics, data, true)
+            {
+                Self::RegistryCoordinator(event)
+            } else if let Ok(event) =
+                StakeRegistry::StakeRegistryEvents::decode_raw_log(topics, data, true)
+            {
+                Self::StakeRegistry(event)
+            } else if let Ok(event) =
+                BLSApkRegistry::BLSApkRegistryEvents::decode_raw_log(topics, data, true)
+            {
+                Self::BlsApkRegistry(event)
+            } else {
+                Self::Erc20(IERC20::IERC20Events::decode_raw_log(topics, data, true).ok()?)
+            };
+            Some(event)
+        };
+
+        Some(Log {
+            address: log.address,
+            data: decode(log)?,
+        })
+    }
+}
+
+/// Outcome of a mined transaction
+#[derive(Debug)]
+pub struct TxOutcome {
+    /// Hash of the transaction
+    pub tx_hash: TxHash,
+    /// Gas used by the transaction
+    pub gas_used: u128,
+    /// Price paid per unit of gas, base fee and priority fee included
+    pub effective_gas_price: u128,
+    /// EigenLayer events emitted by the transaction, in order. Logs of other contracts
+    /// are left out.
+    pub events: Vec<Log<EigenLayerEvent>>,
+    /// Receipt of the transaction
+    pub receipt: TransactionReceipt,
+}
+
+impl From<TransactionReceipt> for TxOutcome {
+    fn from(receipt: TransactionReceipt) -> Self {
+        let events = receipt
+            .inner
+            .logs()
+            .iter()
+            .filter_map(|log| EigenLayerEvent::decode(&log.inner))
+            .collect();
+        TxOutcome {
+            tx_hash: receipt.transaction_hash,
+            gas_used: receipt.gas_used,
+            effective_gas_price: receipt.effective_gas_price,
+            events,
+            receipt,
+        }
+    }
+}
+
+impl TxOutcome {
+    /// Fee paid for the transaction, in wei
+    pub fn fee(&self) -> u128 {
+        self.gas_used * self.effective_gas_price
+    }
+}
+
+/// Transaction mined but reverted
+#[derive(Debug)]
+pub struct TxReverted {
+    /// Outcome of the transaction, without events
+    pub outcome: TxOutcome,
+    /// Revert reason, `None` if the node did not return it or the transaction reverted
+    /// with a custom error.
+    ///
+    /// It is found by replaying the transaction on the state of the parent block, so it
+    /// ignores the transactions mined before it in the same block: a revert caused by
+    /// one of them may have no reason, or a wrong one.
+    pub reason: Option<String>,
+    /// Revert data of the replay, selector included, `None` if the node did not return
+    /// it. Subject to the same limitation as `reason`.
+    pub revert_data: Option<Bytes>,
+}
+
+impl TxReverted {
+    /// Custom error of the revert data, decoded against `E`, the errors of the
+    /// reverting contract
+    pub fn decode_custom_error<E: SolInterface>(&self) -> Option<E> {
+        E::abi_decode(self.revert_data.as_ref()?, true).ok()
+    }
+}
+
+impl fmt::Display for TxReverted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "transaction {} reverted", self.outcome.tx_hash)?;
+        match (&self.reason, &self.revert_data) {
+            (Some(reason), _) => write!(f, ": {reason}"),
+            (None, Some(data)) if !data.is_empty() => write!(f, ": custom error {data}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for TxReverted {}
+
+/// Outcome of `tx`, mined with `receipt` through the node at `rpc_url`.
+///
+/// If it reverted, its revert reason and data are those of `tx` replayed with
+/// `eth_call` on the state of the block before the one it was mined in, or the reason
+/// is `"out of gas"` if the replay succeeds but the transaction used all its gas.
+pub async fn tx_outcome(
+    rpc_url: &str,
+    tx: &TransactionRequest,
+    receipt: TransactionReceipt,
+) -> Result<TxOutcome, Box<TxReverted>> {
+    if receipt.status() {
+        return Ok(receipt.into());
+    }
+
+    let replayed_tx = tx.clone().from(receipt.from);
+    let block = receipt.block_number.map_or(BlockId::latest(), |number| {
+        BlockId::number(number.saturating_sub(1))
+    });
+    let out_of_gas = tx.gas.is_some_and(|gas| receipt.gas_used >= gas);
+    let (reason, revert_data) = match get_provider(rpc_url).call(&replayed_tx).block(block).await {
+        Err(e) => {
+            let reason = match SimulatedRevert::from_error(&e) {
+                Some(SimulatedRevert::Reason(reason)) => Some(reason),
+                _ => None,
+            };
+            (reason, revert_data(&e))
+        }
+        Ok(_) => (out_of_gas.then(|| OUT_OF_GAS.to_string()), None),
+    };
+
+    Err(Box::new(TxReverted {
+        outcome: receipt.into(),
+        reason,
+        revert_data,
+    }))
+}
+
+/// Sends `tx` through `tx_sender` and waits for its receipt, then gets its
+/// [`tx_outcome`] through the node at `rpc_url`.
+///
+/// A failure to send or to get the receipt is mapped with `map_err`, a mined revert
+/// is converted from its [`TxReverted`].
+pub async fn send_with_outcome<E: From<Box<TxReverted>>>(
+    tx_sender: &dyn TxSender,
+    rpc_url: &str,
+    tx: &TransactionRequest,
+    map_err: impl FnOnce(WalletError) -> E,
+) -> Result<TxOutcome, E> {
+    let receipt = tx_sender
+        .send_and_wait_for_receipt(tx.clone(), RECEIPT_POLL_INTERVAL, RECEIPT_TIMEOUT)
+        .await
+        .map_err(map_err)?;
+    Ok(tx_outcome(rpc_url, tx, receipt).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::TxId;
+    use alloy_primitives::{address, Address, LogData, U256};
+    use alloy_sol_types::{SolError, SolEvent};
+    use async_trait::async_trait;
+    use eigen_testing_utils::mock_rpc::mock_rpc;
+    use eigen_utils::binding::ECDSAStakeRegistry::{self, ECDSAStakeRegistryErrors};
+    use serde_json::json;
+
+    /// Sender failing every transaction
+    #[derive(Debug)]
+    struct FailingSender;
+
+    #[async_trait]
+    impl TxSender for FailingSender {
+        async fn sender_address(&self) -> Result<Address, WalletError> {
+            Ok(Address::ZERO)
+        }
+
+        async fn send_transaction(&self, _tx: TransactionRequest) -> Result<TxId, WalletError> {
+            Ok("failed".to_string())
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            tx_id: &TxId,
+        ) -> Result<Option<TransactionReceipt>, WalletError> {
+            Err(WalletError::TransactionFailed {
+                tx_id: tx_id.clone(),
+                reason: "underpriced".to_string(),
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    enum TestError {
+        Send(WalletError),
+        Reverted,
+    }
+
+    impl From<Box<TxReverted>> for TestError {
+        fn from(_: Box<TxReverted>) -> Self {
+            TestError::Reverted
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_with_outcome_maps_send_failure() {
+        let err = send_with_outcome(
+            &FailingSender,
+            "http://localhost:8545",
+            &TransactionRequest::default(),
+            TestError::Send,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TestError::Send(WalletError::TransactionFailed { ref tx_id, .. }) if tx_id == "failed"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tx_outcome_custom_error() {
+        let data = Bytes::from(ECDSAStakeRegistry::InvalidSignature {}.abi_encode());
+        let server = mock_rpc(vec![(
+            "eth_call",
+            Err(json!({
+                "code": 3,
+                "message": "execution reverted",
+                "data": data.to_string(),
+            })),
+        )])
+        .await;
+        let receipt: TransactionReceipt = serde_json::from_value(json!({
+            "type": "0x2",
+            "status": "0x0",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "transactionHash": TxHash::repeat_byte(1),
+            "transactionIndex": "0x0",
+            "blockHash": TxHash::repeat_byte(2),
+            "blockNumber": "0x10",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "from": Address::repeat_byte(3),
+            "to": Address::repeat_byte(4),
+            "contractAddress": null
+        }))
+        .unwrap();
+
+        let reverted = tx_outcome(&server.uri(), &TransactionRequest::default(), receipt)
+            .await
+            .unwrap_err();
+        assert_eq!(reverted.reason, None);
+        assert_eq!(reverted.revert_data, Some(data.clone()));
+        assert!(matches!(
+            reverted.decode_custom_error::<ECDSAStakeRegistryErrors>(),
+            Some(ECDSAStakeRegistryErrors::InvalidSignature(_))
+        ));
+        assert_eq!(
+            reverted.to_string(),
+            format!(
+                "transaction {} reverted: custom error {data}",
+                TxHash::repeat_byte(1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_event() {
+        let strategy_manager = address!("a513E6E4b8f2a923D98304ec87F64353C4D5C853");
+        let deposit = StrategyManager::Deposit {
+            staker: Address::repeat_byte(1),
+            token: Address::repeat_byte(2),
+            strategy: Address::repeat_byte(3),
+            shares: U256::from(100),
+        };
+        let log = Log {
+            address: strategy_manager,
+            data: LogData::new_unchecked(
+                deposit
+                    .encode_topics()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                deposit.encode_data().into(),
+            ),
+        };
+
+        let event = EigenLayerEvent::decode(&log).unwrap();
+        assert_eq!(event.address, strategy_manager);
+        assert!(matches!(
+            event.data,
+            EigenLayerEvent::StrategyManager(StrategyManager::StrategyManagerEvents::Deposit(
+                StrategyManager::Deposit { shares, .. }
+            )) if shares == U256::from(100)
+        ));
+
+        let unknown = Log {
+            address: strategy_manager,
+            data: LogData::new_unchecked(vec![Default::default()], Default::default()),
+        };
+        assert!(EigenLayerEvent::decode(&unknown).is_none());
+    }
+}
@@ -0,0 +1,225 @@
+//! Dry runs of transactions: `eth_call` and `eth_estimateGas` at the pending block,
+//! without signing or sending anything.
+use alloy_primitives::Bytes;
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, TransactionRequest};
+use alloy_sol_types::{Panic, Revert, SolCall, SolError, SolInterface};
+use alloy_transport::TransportError;
+use eigen_utils::{
+    get_provider,
+    revert::{revert_data, revert_reason},
+};
+use std::fmt;
+use thiserror::Error;
+
+/// Error of a simulation that could not tell whether the transaction would succeed
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /// `eth_call` failed without reverting
+    #[error("failed to simulate transaction")]
+    Call(#[source] TransportError),
+
+    /// `eth_estimateGas` failed
+    #[error("failed to estimate transaction gas")]
+    EstimateGas(#[source] TransportError),
+
+    /// Return data does not decode as the return type of the called function
+    #[error("failed to decode simulated return data")]
+    DecodeOutput(#[source] alloy_sol_types::Error),
+}
+
+/// Revert of a simulated transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedRevert {
+    /// `Error(string)` message or `Panic(uint256)` description
+    Reason(String),
+    /// Any other revert data, selector included, e.g. a custom error. Empty for a
+    /// revert without data.
+    Data(Bytes),
+}
+
+impl SimulatedRevert {
+    /// Revert of the call that failed with `err`, `None` if the failure is not a revert
+    pub(crate) fn from_error(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        match revert_data(err) {
+            Some(data)
+                if !data.starts_with(&Revert::SELECTOR) && !data.starts_with(&Panic::SELECTOR) =>
+            {
+                Some(Self::Data(data))
+            }
+            _ => revert_reason(err).map(Self::Reason),
+        }
+    }
+
+    /// Custom error of the revert data, decoded against `E`, the errors of the called
+    /// contract, e.g. `ECDSAStakeRegistry::ECDSAStakeRegistryErrors`
+    pub fn decode<E: SolInterface>(&self) -> Option<E> {
+        match self {
+            Self::Reason(_) => None,
+            Self::Data(data) => E::abi_decode(data, true).ok(),
+        }
+    }
+}
+
+impl fmt::Display for SimulatedRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reason(reason) => f.write_str(reason),
+            Self::Data(data) if data.is_empty() => f.write_str("reverted without data"),
+            Self::Data(data) => write!(f, "custom error {data}"),
+        }
+    }
+}
+
+/// Outcome of a transaction executed at the pending block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSimulation<T, E = SimulatedRevert> {
+    /// Calldata of the transaction
+    pub calldata: Bytes,
+    /// Gas the transaction would use, `None` if it reverts
+    pub gas_estimate: Option<u128>,
+    /// Decoded return data of the called function, or error of the revert
+    pub result: Result<T, E>,
+}
+
+impl<T, E> TxSimulation<T, E> {
+    /// Whether the transaction would succeed
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Simulation with the revert error mapped by `f`
+    pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> TxSimulation<T, F> {
+        TxSimulation {
+            calldata: self.calldata,
+            gas_estimate: self.gas_estimate,
+            result: self.result.map_err(f),
+        }
+    }
+}
+
+/// Simulate `tx`, a call to `C`, through the node at `rpc_url`.
+///
+/// A revert is a successful simulation whose result is the [`SimulatedRevert`]: its
+/// `Error(string)` message or `Panic(uint256)` description, or else its raw data.
+pub async fn simulate<C: SolCall>(
+    rpc_url: &str,
+    tx: &TransactionRequest,
+) -> Result<TxSimulation<C::Return>, SimulationError> {
+    let provider = get_provider(rpc_url);
+    let calldata = tx.input.input().cloned().unwrap_or_default();
+
+    let output = match provider.call(tx).block(BlockId::pending()).await {
+        Ok(output) => output,
+        Err(e) => {
+            let revert = SimulatedRevert::from_error(&e).ok_or(SimulationError::Call(e))?;
+            return Ok(TxSimulation {
+                calldata,
+                gas_estimate: None,
+                result: Err(revert),
+            });
+        }
+    };
+    let gas_estimate = provider
+        .estimate_gas(tx)
+        .block(BlockId::pending())
+        .await
+        .map_err(SimulationError::EstimateGas)?;
+    let result = C::abi_decode_returns(&output, true).map_err(SimulationError::DecodeOutput)?;
+
+    Ok(TxSimulation {
+        calldata,
+        gas_estimate: Some(gas_estimate),
+        result: Ok(result),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+    use alloy_sol_types::SolValue;
+    use eigen_testing_utils::mock_rpc::mock_rpc;
+    use eigen_utils::binding::{
+        ECDSAStakeRegistry::{self, ECDSAStakeRegistryErrors},
+        RegistryCoordinator,
+    };
+    use serde_json::{json, Value};
+
+    /// Simulation of `quorumCount()` against a node answering `eth_call` with `call`
+    async fn simulate_quorum_count(
+        call: Result<Value, Value>,
+    ) -> Result<TxSimulation<RegistryCoordinator::quorumCountReturn>, SimulationError> {
+        let server = mock_rpc(vec![
+            ("eth_call", call),
+            ("eth_estimateGas", Ok(json!("0x5208"))),
+        ])
+        .await;
+        let tx = TransactionRequest::default()
+            .input(RegistryCoordinator::quorumCountCall {}.abi_encode().into());
+        simulate::<RegistryCoordinator::quorumCountCall>(&server.uri(), &tx).await
+    }
+
+    fn revert_error(data: Vec<u8>) -> Result<Value, Value> {
+        Err(json!({
+            "code": 3,
+            "message": "execution reverted",
+            "data": Bytes::from(data).to_string(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_simulate_success() {
+        let output = Bytes::from(U256::from(2).abi_encode()).to_string();
+        let simulation = simulate_quorum_count(Ok(json!(output))).await.unwrap();
+
+        assert_eq!(simulation.result.unwrap()._0, 2);
+        assert_eq!(simulation.gas_estimate, Some(21000));
+        assert_eq!(
+            simulation.calldata,
+            Bytes::from(RegistryCoordinator::quorumCountCall {}.abi_encode())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_revert_reason() {
+        let data = Revert::from("Pausable: index is paused").abi_encode();
+        let simulation = simulate_quorum_count(revert_error(data)).await.unwrap();
+
+        assert_eq!(simulation.gas_estimate, None);
+        assert_eq!(
+            simulation.result.unwrap_err(),
+            SimulatedRevert::Reason("Pausable: index is paused".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_custom_error() {
+        let data = ECDSAStakeRegistry::InvalidSignature {}.abi_encode();
+        let simulation = simulate_quorum_count(revert_error(data.clone()))
+            .await
+            .unwrap();
+
+        let revert = simulation.result.unwrap_err();
+        assert_eq!(revert, SimulatedRevert::Data(data.into()));
+        assert!(matches!(
+            revert.decode::<ECDSAStakeRegistryErrors>(),
+            Some(ECDSAStakeRegistryErrors::InvalidSignature(_))
+        ));
+
+        let simulation = simulate_quorum_count(revert_error(vec![])).await.unwrap();
+        assert_eq!(
+            simulation.result.unwrap_err().to_string(),
+            "reverted without data"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_call_failure() {
+        let error = Err(json!({"code": -32000, "message": "header not found"}));
+        assert!(matches!(
+            simulate_quorum_count(error).await,
+            Err(SimulationError::Call(_))
+        ));
+    }
+}
@@ -0,0 +1,230 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, Signature, TxHash};
+use alloy_provider::Provider;
+use alloy_rpc_types::{TransactionReceipt, TransactionRequest};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
+use eigen_utils::{get_provider, get_signer};
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::Instant;
+
+/// Identifier returned by a [`TxSender`] for a submitted transaction. It is the
+/// transaction hash for local signers and the custodian's transaction id otherwise.
+pub type TxId = String;
+
+/// Boxed source error of a [`WalletError`]
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Error returned by a [`TxSender`]
+#[derive(Debug, Error)]
+pub enum WalletError {
+    /// Failed to get the sender address
+    #[error("failed to get sender address")]
+    SenderAddress(#[source] BoxError),
+
+    /// Failed to send transaction
+    #[error("failed to send transaction")]
+    SendTransaction(#[source] BoxError),
+
+    /// Failed to get the receipt of a transaction
+    #[error("failed to get transaction receipt of {tx_id}")]
+    GetTransactionReceipt {
+        tx_id: TxId,
+        #[source]
+        source: BoxError,
+    },
+
+    /// The transaction was not mined in time, e.g. dropped or underpriced
+    #[error("transaction {tx_id} not mined after {timeout:?}")]
+    ReceiptTimeout { tx_id: TxId, timeout: Duration },
+
+    /// The transaction will never be mined
+    #[error("transaction {tx_id} failed: {reason}")]
+    TransactionFailed { tx_id: TxId, reason: String },
+
+    /// Failed to sign EIP-712 typed data
+    #[error("failed to sign typed data")]
+    SignTypedData(#[source] BoxError),
+}
+
+/// Sends transactions on behalf of a single account and tracks them until mined.
+#[async_trait]
+pub trait TxSender: Debug + Send + Sync {
+    /// Address the transactions are sent from
+    async fn sender_address(&self) -> Result<Address, WalletError>;
+
+    /// Submits `tx`, returning the id to track it with
+    async fn send_transaction(&self, tx: TransactionRequest) -> Result<TxId, WalletError>;
+
+    /// Receipt of transaction `tx_id`, `None` while it is not mined yet
+    async fn get_transaction_receipt(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<Option<TransactionReceipt>, WalletError>;
+
+    /// Polls the receipt of transaction `tx_id` every `poll_interval` until it is mined,
+    /// giving up with [`WalletError::ReceiptTimeout`] after `timeout`
+    async fn wait_for_receipt(
+        &self,
+        tx_id: &TxId,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<TransactionReceipt, WalletError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(receipt) = self.get_transaction_receipt(tx_id).await? {
+                return Ok(receipt);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(WalletError::ReceiptTimeout {
+                    tx_id: tx_id.clone(),
+                    timeout,
+                });
+            }
+            tokio::time::sleep(poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    /// Submits `tx` and waits until it is mined, polling every `poll_interval` for at
+    /// most `timeout`
+    async fn send_and_wait_for_receipt(
+        &self,
+        tx: TransactionRequest,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<TransactionReceipt, WalletError> {
+        let tx_id = self.send_transaction(tx).await?;
+        self.wait_for_receipt(&tx_id, poll_interval, timeout).await
+    }
+}
+
+/// Signs EIP-712 typed data on behalf of a single account, e.g. the operator
+/// registration and delegation approval signatures checked by EigenLayer contracts.
+#[async_trait]
+pub trait Eip712Signer: Debug + Send + Sync {
+    /// Address recovered from the signatures
+    async fn signer_address(&self) -> Result<Address, WalletError>;
+
+    /// ECDSA signature over the EIP-712 signing hash of `typed_data`
+    async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, WalletError>;
+}
+
+/// [`TxSender`] and [`Eip712Signer`] signing locally with a private key
+#[derive(Debug, Clone)]
+pub struct PrivateKeyWallet {
+    signer: String,
+    provider: String,
+}
+
+impl PrivateKeyWallet {
+    /// New PrivateKeyWallet from a hex encoded private key and a rpc url
+    pub fn new(signer: String, provider: String) -> Self {
+        Self { signer, provider }
+    }
+}
+
+#[async_trait]
+impl TxSender for PrivateKeyWallet {
+    async fn sender_address(&self) -> Result<Address, WalletError> {
+        let wallet = PrivateKeySigner::from_str(&self.signer)
+            .map_err(|e| WalletError::SenderAddress(e.into()))?;
+        Ok(wallet.address())
+    }
+
+    async fn send_transaction(&self, tx: TransactionRequest) -> Result<TxId, WalletError> {
+        let provider = get_signer(self.signer.clone(), &self.provider);
+
+        let pending_tx = provider
+            .send_transaction(tx)
+            .await
+            .map_err(|e| WalletError::SendTransaction(e.into()))?;
+        Ok(pending_tx.tx_hash().to_string())
+    }
+
+    async fn get_transaction_receipt(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<Option<TransactionReceipt>, WalletError> {
+        let tx_hash = TxHash::from_str(tx_id).map_err(|e| WalletError::GetTransactionReceipt {
+            tx_id: tx_id.clone(),
+            source: e.into(),
+        })?;
+        let provider = get_provider(&self.provider);
+
+        provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .map_err(|e| WalletError::GetTransactionReceipt {
+                tx_id: tx_id.clone(),
+                source: e.into(),
+            })
+    }
+}
+
+#[async_trait]
+impl Eip712Signer for PrivateKeyWallet {
+    async fn signer_address(&self) -> Result<Address, WalletError> {
+        TxSender::sender_address(self).await
+    }
+
+    async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, WalletError> {
+        let wallet = PrivateKeySigner::from_str(&self.signer)
+            .map_err(|e| WalletError::SignTypedData(e.into()))?;
+        let hash = typed_data
+            .eip712_signing_hash()
+            .map_err(|e| WalletError::SignTypedData(e.into()))?;
+
+        wallet
+            .sign_hash_sync(&hash)
+            .map_err(|e| WalletError::SignTypedData(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sender whose transactions are never mined
+    #[derive(Debug)]
+    struct DroppingSender;
+
+    #[async_trait]
+    impl TxSender for DroppingSender {
+        async fn sender_address(&self) -> Result<Address, WalletError> {
+            Ok(Address::ZERO)
+        }
+
+        async fn send_transaction(&self, _tx: TransactionRequest) -> Result<TxId, WalletError> {
+            Ok("dropped".to_string())
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            _tx_id: &TxId,
+        ) -> Result<Option<TransactionReceipt>, WalletError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receipt_timeout() {
+        let err = DroppingSender
+            .send_and_wait_for_receipt(
+                TransactionRequest::default(),
+                Duration::from_millis(10),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            WalletError::ReceiptTimeout { ref tx_id, .. } if tx_id == "dropped"
+        ));
+    }
+}
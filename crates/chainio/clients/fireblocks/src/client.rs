@@ -1,38 +1,120 @@
+use crate::error::FireblocksError;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
 
-use serde::{Deserialize, Serialize};
+/// Lifetime of the JWT signed for each request, in seconds.
+const JWT_EXPIRY_SECONDS: u64 = 30;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// Fireblocks asset id of the native token of a chain.
+///
+/// Assets unknown to the SDK, like the ids of workspace specific test assets, are
+/// [`AssetID::Custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum AssetID {
+    /// Ether on mainnet
     ETH,
+    /// Ether on Goerli
     GoerliETH,
+    /// Ether on Sepolia
+    SepoliaETH,
+    /// Ether on Holesky
+    HoleskyETH,
+    /// Any other Fireblocks asset id
+    Custom(String),
+}
+
+impl AssetID {
+    /// Fireblocks id of the asset
+    pub fn as_str(&self) -> &str {
+        match self {
+            AssetID::ETH => "ETH",
+            AssetID::GoerliETH => "ETH_TEST3",
+            AssetID::SepoliaETH => "ETH_TEST5",
+            AssetID::HoleskyETH => "ETH_TEST6",
+            AssetID::Custom(id) => id,
+        }
+    }
 }
 
 impl std::fmt::Display for AssetID {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                AssetID::ETH => "ETH",
-                AssetID::GoerliETH => "GoerliETH",
-            }
-        )
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for AssetID {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "ETH" => AssetID::ETH,
+            "ETH_TEST3" => AssetID::GoerliETH,
+            "ETH_TEST5" => AssetID::SepoliaETH,
+            "ETH_TEST6" => AssetID::HoleskyETH,
+            _ => AssetID::Custom(id),
+        }
     }
 }
 
-// Initialize AssetIDByChain as a HashMap
-#[allow(unused)]
-fn asset_id_by_chain() -> HashMap<u64, AssetID> {
-    let mut map = HashMap::new();
-    map.insert(1, AssetID::ETH);
-    map.insert(2, AssetID::GoerliETH);
-    map
+impl From<&str> for AssetID {
+    fn from(id: &str) -> Self {
+        AssetID::from(id.to_string())
+    }
 }
 
-#[allow(unused)]
-#[allow(non_camel_case_types)]
-struct client {}
+impl From<AssetID> for String {
+    fn from(asset_id: AssetID) -> Self {
+        asset_id.as_str().to_string()
+    }
+}
+
+/// Fireblocks asset paying for the transactions of each chain, by chain id.
+///
+/// The default mapping covers mainnet, Goerli, Sepolia and Holesky. Workspaces using
+/// other asset ids, or other chains, override it with [`ChainAssets::with_asset`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainAssets {
+    assets: HashMap<u64, AssetID>,
+}
+
+impl Default for ChainAssets {
+    fn default() -> Self {
+        Self {
+            assets: HashMap::from([
+                (1, AssetID::ETH),
+                (5, AssetID::GoerliETH),
+                (11155111, AssetID::SepoliaETH),
+                (17000, AssetID::HoleskyETH),
+            ]),
+        }
+    }
+}
+
+impl ChainAssets {
+    /// Mapping without any chain
+    pub fn empty() -> Self {
+        Self {
+            assets: HashMap::new(),
+        }
+    }
+
+    /// Uses `asset_id` for the chain `chain_id`, replacing the previous asset if any.
+    pub fn with_asset(mut self, chain_id: u64, asset_id: impl Into<AssetID>) -> Self {
+        self.assets.insert(chain_id, asset_id.into());
+        self
+    }
+
+    /// Asset of the chain `chain_id`
+    pub fn asset_id(&self, chain_id: u64) -> Result<&AssetID, FireblocksError> {
+        self.assets
+            .get(&chain_id)
+            .ok_or(FireblocksError::UnsupportedChain(chain_id))
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ErrorResponse {
@@ -40,8 +122,294 @@ pub struct ErrorResponse {
     pub code: i32,
 }
 
-#[test]
-fn test_asset_id_by_chain() {
-    let asset_id_by_chain = asset_id_by_chain();
-    assert_eq!(AssetID::ETH, *asset_id_by_chain.get(&1).unwrap());
+/// Claims of the JWT Fireblocks expects in the `Authorization` header of every request.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct Claims {
+    /// Request path including the query string
+    pub(crate) uri: String,
+    pub(crate) nonce: String,
+    pub(crate) iat: u64,
+    pub(crate) exp: u64,
+    /// Fireblocks API key
+    pub(crate) sub: String,
+    /// Hex encoded sha256 of the request body
+    #[serde(rename = "bodyHash")]
+    pub(crate) body_hash: String,
+}
+
+/// Fireblocks API client. Every request is authenticated with an RS256 JWT signed with
+/// the API secret key.
+#[derive(Clone)]
+pub struct FireblocksClient {
+    api_key: String,
+    secret_key: EncodingKey,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for FireblocksClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FireblocksClient")
+            .field("api_key", &self.api_key)
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FireblocksClient {
+    /// New FireblocksClient instance
+    ///
+    /// `secret_key` is the PEM encoded RSA key of the API user and `base_url` the
+    /// Fireblocks API url, e.g. `https://api.fireblocks.io`.
+    pub fn new(
+        api_key: String,
+        secret_key: &[u8],
+        base_url: String,
+        timeout: Duration,
+    ) -> Result<Self, FireblocksError> {
+        let secret_key =
+            EncodingKey::from_rsa_pem(secret_key).map_err(FireblocksError::InvalidSecretKey)?;
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(FireblocksError::BuildHttpClient)?;
+
+        Ok(Self {
+            api_key,
+            secret_key,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client,
+        })
+    }
+
+    /// Sends a signed `GET` request to `path` and deserializes the response.
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, FireblocksError> {
+        self.request(Method::GET, path, None).await
+    }
+
+    /// Sends a signed `POST` request with a json `body` to `path` and deserializes the
+    /// response.
+    pub async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, FireblocksError> {
+        let body = serde_json::to_vec(body)?;
+        self.request(Method::POST, path, Some(body)).await
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+    ) -> Result<T, FireblocksError> {
+        let body = body.unwrap_or_default();
+        let token = self.sign_jwt(path, &body)?;
+
+        let response = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Content-Type", "application/json")
+            .header("X-API-Key", &self.api_key)
+            .bearer_auth(token)
+            .body(body)
+            .send()
+            .await
+            .map_err(|source| FireblocksError::Request {
+                path: path.to_string(),
+                source,
+            })?;
+
+        let status = response.status();
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|source| FireblocksError::Request {
+                path: path.to_string(),
+                source,
+            })?;
+
+        if !status.is_success() {
+            return Err(error_from_response(status, &bytes));
+        }
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Signs the JWT for a request to `path` (including the query string) with `body`.
+    pub(crate) fn sign_jwt(&self, path: &str, body: &[u8]) -> Result<String, FireblocksError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let claims = Claims {
+            uri: path.to_string(),
+            nonce: Uuid::new_v4().to_string(),
+            iat: now,
+            exp: now + JWT_EXPIRY_SECONDS,
+            sub: self.api_key.clone(),
+            body_hash: hex::encode(Sha256::digest(body)),
+        };
+
+        jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.secret_key)
+            .map_err(FireblocksError::SignJwt)
+    }
+}
+
+fn error_from_response(status: StatusCode, body: &[u8]) -> FireblocksError {
+    match serde_json::from_slice::<ErrorResponse>(body) {
+        Ok(ErrorResponse { message, code }) => FireblocksError::Api {
+            status: status.as_u16(),
+            code,
+            message,
+        },
+        Err(_) => FireblocksError::UnexpectedResponse {
+            status: status.as_u16(),
+            body: String::from_utf8_lossy(body).into_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server, API_KEY};
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    #[test]
+    fn test_asset_id_serde() {
+        for (asset_id, id) in [
+            (AssetID::ETH, "ETH"),
+            (AssetID::GoerliETH, "ETH_TEST3"),
+            (AssetID::SepoliaETH, "ETH_TEST5"),
+            (AssetID::HoleskyETH, "ETH_TEST6"),
+            (
+                AssetID::Custom("ETH_TEST_HOLESKY".to_string()),
+                "ETH_TEST_HOLESKY",
+            ),
+        ] {
+            assert_eq!(serde_json::to_value(&asset_id).unwrap(), json!(id));
+            assert_eq!(
+                serde_json::from_value::<AssetID>(json!(id)).unwrap(),
+                asset_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_chain_assets() {
+        let chain_assets = ChainAssets::default();
+        assert_eq!(chain_assets.asset_id(1).unwrap(), &AssetID::ETH);
+        assert_eq!(chain_assets.asset_id(5).unwrap(), &AssetID::GoerliETH);
+        assert_eq!(
+            chain_assets.asset_id(11155111).unwrap(),
+            &AssetID::SepoliaETH
+        );
+        assert_eq!(chain_assets.asset_id(17000).unwrap(), &AssetID::HoleskyETH);
+        assert!(matches!(
+            chain_assets.asset_id(2),
+            Err(FireblocksError::UnsupportedChain(2))
+        ));
+
+        let chain_assets = chain_assets.with_asset(17000, "ETH_TEST_HOLESKY");
+        assert_eq!(
+            chain_assets.asset_id(17000).unwrap(),
+            &AssetID::Custom("ETH_TEST_HOLESKY".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_is_signed() {
+        let server = mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/supported_assets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"id": "ETH"}])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let assets: Value = client.get("/v1/supported_assets").await.unwrap();
+
+        assert_eq!(assets, json!([{"id": "ETH"}]));
+    }
+
+    #[tokio::test]
+    async fn test_post_body_hash() {
+        let server = mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "1"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let response: Value = client
+            .post("/v1/transactions", &json!({"operation": "CONTRACT_CALL"}))
+            .await
+            .unwrap();
+
+        assert_eq!(response, json!({"id": "1"}));
+    }
+
+    #[tokio::test]
+    async fn test_error_response() {
+        let server = mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/transactions/unknown"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(json!({"message": "Transaction not found", "code": 1404})),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let err = client
+            .get::<Value>("/v1/transactions/unknown")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FireblocksError::Api { status: 400, code: 1404, ref message } if message == "Transaction not found"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_request_is_rejected() {
+        let server = mock_server().await;
+        let client = FireblocksClient::new(
+            API_KEY.to_string(),
+            include_bytes!("../testdata/other_secret_key.pem"),
+            server.uri(),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+
+        let err = client
+            .get::<Value>("/v1/supported_assets")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, FireblocksError::Api { status: 401, .. }));
+    }
+
+    #[test]
+    fn test_invalid_secret_key() {
+        let err = FireblocksClient::new(
+            API_KEY.to_string(),
+            b"not a pem key",
+            "http://localhost".to_string(),
+            Duration::from_secs(5),
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, FireblocksError::InvalidSecretKey(_)));
+    }
 }
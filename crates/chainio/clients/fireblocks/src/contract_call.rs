@@ -1,15 +1,22 @@
-use crate::client::AssetID;
+use crate::client::{AssetID, FireblocksClient};
+use crate::error::FireblocksError;
 use crate::status::Status;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransactionOperation {
+    #[serde(rename = "CONTRACT_CALL")]
     ContractCall,
+    #[serde(rename = "TRANSFER")]
     Transfer,
+    #[serde(rename = "MINT")]
     Mint,
+    #[serde(rename = "BURN")]
     Burn,
+    #[serde(rename = "TYPED_MESSAGE")]
     TypedMessage,
+    #[serde(rename = "RAW")]
     Raw,
 }
 
@@ -26,20 +33,39 @@ impl TransactionOperation {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct Account {
     #[serde(rename = "type")]
-    type_field: String,
-    id: String,
+    pub type_field: String,
+    #[serde(default)]
+    pub id: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl Account {
+    /// Vault account `id` of the workspace.
+    pub fn vault_account(id: String) -> Self {
+        Account {
+            type_field: "VAULT_ACCOUNT".to_string(),
+            id,
+        }
+    }
+
+    /// Whitelisted external wallet or contract `id`.
+    pub fn external_wallet(id: String) -> Self {
+        Account {
+            type_field: "EXTERNAL_WALLET".to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ExtraParams {
-    #[serde(rename = "ContractCallData")]
-    calldata: String,
+    #[serde(rename = "contractCallData")]
+    pub calldata: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ContractCallRequest {
     operation: TransactionOperation,
     #[serde(rename = "externalTxId")]
@@ -51,30 +77,136 @@ pub struct ContractCallRequest {
     amount: String,
     #[serde(rename = "extraParameters")]
     extra_parameters: ExtraParams,
-    #[serde(rename = "replaceTxByHash")]
-    replace_tx_by_hash: String,
-}
-
-#[allow(unused)]
-pub struct ContractCallResponse {
-    id: String,
-    status: Status,
+    #[serde(rename = "replaceTxByHash", skip_serializing_if = "Option::is_none")]
+    replace_tx_by_hash: Option<String>,
+    #[serde(rename = "gasLimit", skip_serializing_if = "Option::is_none")]
+    gas_limit: Option<String>,
+    #[serde(rename = "gasPrice", skip_serializing_if = "Option::is_none")]
+    gas_price: Option<String>,
+    #[serde(rename = "maxFee", skip_serializing_if = "Option::is_none")]
+    max_fee: Option<String>,
+    #[serde(rename = "priorityFee", skip_serializing_if = "Option::is_none")]
+    priority_fee: Option<String>,
 }
 
-// impl ContractCallRequest{
-
-//     pub fn new(external_tx_id:String,asset_id : AssetID,source_account_id : String, destination_account_id: String,amount:String,calldata:String,replace_tx_by_hash:String )-> Self{
-
-//         ContractCallRequest{
+impl ContractCallRequest {
+    /// New `CONTRACT_CALL` request from the vault account `source_account_id` to the
+    /// whitelisted contract `destination_account_id`.
+    ///
+    /// `calldata` is the hex encoded call data and `replace_tx_by_hash` the hash of a
+    /// pending transaction this one replaces.
+    pub fn new(
+        external_tx_id: String,
+        asset_id: AssetID,
+        source_account_id: String,
+        destination_account_id: String,
+        amount: String,
+        calldata: String,
+        replace_tx_by_hash: Option<String>,
+    ) -> Self {
+        ContractCallRequest {
+            operation: TransactionOperation::ContractCall,
+            external_tx_id,
+            asset_id,
+            source: Account::vault_account(source_account_id),
+            destination: Account::external_wallet(destination_account_id),
+            amount,
+            extra_parameters: ExtraParams { calldata },
+            replace_tx_by_hash,
+            gas_limit: None,
+            gas_price: None,
+            max_fee: None,
+            priority_fee: None,
+        }
+    }
 
-//             operation: ContractCallResponse
+    /// Sets the gas limit of the transaction.
+    pub fn with_gas_limit(mut self, gas_limit: String) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
 
-//         }
+    /// Sets the legacy gas price, in Gwei.
+    pub fn with_gas_price(mut self, gas_price: String) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
 
-//     }
+    /// Sets the EIP-1559 max fee and priority fee per gas, in Gwei.
+    pub fn with_eip1559_fees(mut self, max_fee: String, priority_fee: String) -> Self {
+        self.max_fee = Some(max_fee);
+        self.priority_fee = Some(priority_fee);
+        self
+    }
+}
 
-//     pub fn  contract_call(&self,req: ContractCallRequest) -> ContractCallResponse{
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractCallResponse {
+    pub id: String,
+    pub status: Status,
+}
 
-//     }
+impl FireblocksClient {
+    /// Submits a contract call transaction, returning the Fireblocks transaction id and
+    /// its initial status.
+    pub async fn contract_call(
+        &self,
+        req: &ContractCallRequest,
+    ) -> Result<ContractCallResponse, FireblocksError> {
+        self.post("/v1/transactions", req).await
+    }
+}
 
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn test_contract_call() {
+        let server = mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .and(body_json(json!({
+                "operation": "CONTRACT_CALL",
+                "externalTxId": "register-operator-1",
+                "assetId": "ETH",
+                "source": {"type": "VAULT_ACCOUNT", "id": "0"},
+                "destination": {"type": "EXTERNAL_WALLET", "id": "c1b5a2e4"},
+                "amount": "0",
+                "extraParameters": {"contractCallData": "0xdeadbeef"},
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"id": "tx-1", "status": "SUBMITTED"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let req = ContractCallRequest::new(
+            "register-operator-1".to_string(),
+            AssetID::ETH,
+            "0".to_string(),
+            "c1b5a2e4".to_string(),
+            "0".to_string(),
+            "0xdeadbeef".to_string(),
+            None,
+        );
+        let response = client.contract_call(&req).await.unwrap();
+
+        assert_eq!(
+            response,
+            ContractCallResponse {
+                id: "tx-1".to_string(),
+                status: Status::Submitted,
+            }
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{client::AssetID, status::Status};
+use alloy_primitives::{utils::UnitsError, Address};
+use alloy_transport::TransportError;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error returned by the Fireblocks client
+#[derive(Debug, Error)]
+pub enum FireblocksError {
+    /// API secret key is not a valid RSA PEM key
+    #[error("invalid Fireblocks API secret key")]
+    InvalidSecretKey(#[source] jsonwebtoken::errors::Error),
+
+    /// Failed to sign the request JWT
+    #[error("failed to sign Fireblocks request JWT")]
+    SignJwt(#[source] jsonwebtoken::errors::Error),
+
+    /// Failed to build the http client
+    #[error("failed to build http client")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    /// Http request failed before a response was received
+    #[error("Fireblocks request to {path} failed")]
+    Request {
+        path: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// Fireblocks answered with an error response
+    #[error("error response ({status}) from Fireblocks with code {code}: {message}")]
+    Api {
+        status: u16,
+        code: i32,
+        message: String,
+    },
+
+    /// Fireblocks answered with a non success status and an unexpected body
+    #[error("unexpected response ({status}) from Fireblocks: {body}")]
+    UnexpectedResponse { status: u16, body: String },
+
+    /// Failed to serialize a request body or deserialize a response body
+    #[error("failed to (de)serialize Fireblocks payload")]
+    Serde(#[from] serde_json::Error),
+
+    /// Transaction reached a terminal status other than completed
+    #[error("Fireblocks transaction {tx_id} ended with status {status:?} ({sub_status})")]
+    TransactionNotCompleted {
+        tx_id: String,
+        status: Status,
+        sub_status: String,
+    },
+
+    /// Transaction did not reach a terminal status in time
+    #[error("Fireblocks transaction {tx_id} still has status {status:?} ({sub_status}) after {timeout:?}")]
+    WaitTimeout {
+        tx_id: String,
+        status: Status,
+        sub_status: String,
+        timeout: Duration,
+    },
+
+    /// Completed transaction carries an invalid on-chain hash
+    #[error("Fireblocks transaction {tx_id} has invalid tx hash {tx_hash:?}")]
+    InvalidTxHash { tx_id: String, tx_hash: String },
+
+    /// Json-rpc request to the chain failed
+    #[error("rpc request failed")]
+    Rpc(#[from] TransportError),
+
+    /// No Fireblocks asset is known for the chain id
+    #[error("no Fireblocks asset for chain id {0}")]
+    UnsupportedChain(u64),
+
+    /// Destination address is not a whitelisted contract for the wallet asset
+    #[error("contract {0} is not whitelisted in Fireblocks")]
+    ContractNotWhitelisted(Address),
+
+    /// Fireblocks contract calls can not deploy contracts
+    #[error("contract creation is not supported by Fireblocks contract calls")]
+    ContractCreationNotSupported,
+
+    /// No vault account owns the address
+    #[error("no Fireblocks vault account owns address {0}")]
+    VaultAccountNotFound(Address),
+
+    /// Vault account holds no address for the asset
+    #[error("vault account {vault_id} has no address for asset {asset_id}")]
+    NoAssetAddress { vault_id: String, asset_id: AssetID },
+
+    /// Transaction to replace has no on-chain hash yet
+    #[error("Fireblocks transaction {0} is not broadcast yet and can not be replaced")]
+    NotYetBroadcast(String),
+
+    /// Transaction to replace is already completed
+    #[error("Fireblocks transaction {0} is already completed and can not be replaced")]
+    TransactionAlreadyCompleted(String),
+
+    /// Failed to convert an amount to the units Fireblocks expects
+    #[error("invalid amount")]
+    Units(#[from] UnitsError),
+
+    /// Typed data can not be hashed
+    #[error("invalid EIP-712 typed data")]
+    InvalidTypedData(#[source] alloy_dyn_abi::Error),
+
+    /// Completed typed message transaction carries no signature
+    #[error("Fireblocks transaction {0} has no signed message")]
+    NoSignedMessage(String),
+
+    /// Signature of a typed message transaction is malformed
+    #[error("Fireblocks transaction {0} has an invalid signature")]
+    InvalidSignature(String),
+
+    /// Signature does not recover to the vault account address
+    #[error(
+        "signature of Fireblocks transaction {tx_id} recovers to {recovered}, expected {expected}"
+    )]
+    UnexpectedSigner {
+        tx_id: String,
+        expected: Address,
+        recovered: Address,
+    },
+}
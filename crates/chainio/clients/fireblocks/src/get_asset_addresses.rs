@@ -0,0 +1,33 @@
+use crate::{
+    client::{AssetID, FireblocksClient},
+    error::FireblocksError,
+};
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssetAddress {
+    #[serde(rename = "assetId")]
+    pub asset_id: AssetID,
+    pub address: Address,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tag: String,
+    #[serde(rename = "type", default)]
+    pub type_field: String,
+}
+
+impl FireblocksClient {
+    /// Deposit addresses of `asset_id` in the vault account `vault_id`.
+    pub async fn get_asset_addresses(
+        &self,
+        vault_id: &str,
+        asset_id: &AssetID,
+    ) -> Result<Vec<AssetAddress>, FireblocksError> {
+        self.get(&format!(
+            "/v1/vault/accounts/{vault_id}/{asset_id}/addresses"
+        ))
+        .await
+    }
+}
@@ -1,82 +1,308 @@
 use crate::{
-    client::AssetID,
+    client::{AssetID, FireblocksClient},
     contract_call::{Account, TransactionOperation},
+    error::FireblocksError,
     status::Status,
 };
+use alloy_primitives::TxHash;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::debug;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct AmountInfo {
-    amount: String,
-    #[serde(rename = "requestedAmount")]
-    requested_amount: String,
-    #[serde(rename = "netAmount")]
-    net_amount: String,
-    #[serde(rename = "amountUSD")]
-    amount_usdc: String,
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AmountInfo {
+    #[serde(default)]
+    pub amount: String,
+    #[serde(rename = "requestedAmount", default)]
+    pub requested_amount: String,
+    #[serde(rename = "netAmount", default)]
+    pub net_amount: String,
+    #[serde(rename = "amountUSD", default)]
+    pub amount_usdc: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct FeeInfo {
-    #[serde(rename = "networkFee")]
-    network_fee: String,
-    #[serde(rename = "serviceFee")]
-    service_fee: String,
-    #[serde(rename = "gasPrice")]
-    gas_price: String,
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeeInfo {
+    #[serde(rename = "networkFee", default)]
+    pub network_fee: String,
+    #[serde(rename = "serviceFee", default)]
+    pub service_fee: String,
+    #[serde(rename = "gasPrice", default)]
+    pub gas_price: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct ExtraParameters {
-    #[serde(rename = "contractCallData")]
-    contract_calldata: String,
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExtraParameters {
+    #[serde(rename = "contractCallData", default)]
+    pub contract_calldata: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct BlockInfo {
-    #[serde(rename = "blockHeight")]
-    block_height: String,
-    #[serde(rename = "blockHash")]
-    block_hash: String,
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BlockInfo {
+    #[serde(rename = "blockHeight", default)]
+    pub block_height: String,
+    #[serde(rename = "blockHash", default)]
+    pub block_hash: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Transaction {
-    id: String,
-    #[serde(rename = "externalId")]
-    external_id: String,
-    status: Status,
-    #[serde(rename = "subStatus")]
-    sub_status: String,
-    #[serde(rename = "txHash")]
-    tx_hash: String,
-    operation: TransactionOperation,
-    #[serde(rename = "createdAt")]
-    created_at: i64,
-    #[serde(rename = "lastUpdated")]
-    last_updated: i64,
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MessageSignature {
+    #[serde(rename = "fullSig", default)]
+    pub full_sig: String,
+    #[serde(default)]
+    pub r: String,
+    #[serde(default)]
+    pub s: String,
+    #[serde(default)]
+    pub v: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignedMessage {
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub algorithm: String,
+    #[serde(rename = "publicKey", default)]
+    pub public_key: String,
+    pub signature: MessageSignature,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Transaction {
+    pub id: String,
+    #[serde(rename = "externalTxId", default)]
+    pub external_id: String,
+    pub status: Status,
+    #[serde(rename = "subStatus", default)]
+    pub sub_status: String,
+    #[serde(rename = "txHash", default)]
+    pub tx_hash: String,
+    pub operation: TransactionOperation,
+    #[serde(rename = "createdAt", default)]
+    pub created_at: i64,
+    #[serde(rename = "lastUpdated", default)]
+    pub last_updated: i64,
     #[serde(rename = "assetId")]
-    asset_id: AssetID,
-    source: Account,
-    #[serde(rename = "sourceAddress")]
-    source_address: String,
-    destination: Account,
-    #[serde(rename = "DestinationAddress")]
-    destination_address: String,
-    #[serde(rename = "DestinationAddressDescription")]
-    destination_address_description: String,
-    #[serde(rename = "DestinationTag")]
-    destination_tag: String,
-    #[serde(rename = "amountInfo")]
-    amount_info: AmountInfo,
-    #[serde(rename = "feeInfo")]
-    fee_info: FeeInfo,
-    #[serde(rename = "feeCurrency")]
-    fee_currency: String,
-    #[serde(rename = "extraParameters")]
-    extra_parameters: ExtraParameters,
-    #[serde(rename = "numOfConfirmations")]
-    num_of_confirmations: i64,
-    #[serde(rename = "blockInfo")]
-    block_info: BlockInfo,
+    pub asset_id: AssetID,
+    pub source: Account,
+    #[serde(rename = "sourceAddress", default)]
+    pub source_address: String,
+    #[serde(default)]
+    pub destination: Account,
+    #[serde(rename = "destinationAddress", default)]
+    pub destination_address: String,
+    #[serde(rename = "destinationAddressDescription", default)]
+    pub destination_address_description: String,
+    #[serde(rename = "destinationTag", default)]
+    pub destination_tag: String,
+    #[serde(rename = "amountInfo", default)]
+    pub amount_info: AmountInfo,
+    #[serde(rename = "feeInfo", default)]
+    pub fee_info: FeeInfo,
+    #[serde(rename = "feeCurrency", default)]
+    pub fee_currency: String,
+    #[serde(rename = "extraParameters", default)]
+    pub extra_parameters: ExtraParameters,
+    #[serde(rename = "numOfConfirmations", default)]
+    pub num_of_confirmations: i64,
+    #[serde(rename = "blockInfo", default)]
+    pub block_info: BlockInfo,
+    /// Signatures of a `TYPED_MESSAGE` or `RAW` transaction
+    #[serde(rename = "signedMessages", default)]
+    pub signed_messages: Vec<SignedMessage>,
+}
+
+impl FireblocksClient {
+    /// Get the transaction with Fireblocks id `tx_id`.
+    pub async fn get_transaction(&self, tx_id: &str) -> Result<Transaction, FireblocksError> {
+        self.get(&format!("/v1/transactions/{tx_id}")).await
+    }
+
+    /// Polls the transaction `tx_id` every `poll_interval` until it reaches a terminal
+    /// status, returning its on-chain hash once completed.
+    ///
+    /// Failed, rejected, blocked and cancelled transactions return
+    /// [`FireblocksError::TransactionNotCompleted`] with the Fireblocks sub-status, and
+    /// transactions still pending after `timeout`, e.g. waiting for an approver,
+    /// [`FireblocksError::WaitTimeout`].
+    pub async fn wait_for_terminal(
+        &self,
+        tx_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<TxHash, FireblocksError> {
+        let tx = self
+            .wait_for_completed(tx_id, poll_interval, timeout)
+            .await?;
+        TxHash::from_str(&tx.tx_hash).map_err(|_| FireblocksError::InvalidTxHash {
+            tx_id: tx_id.to_string(),
+            tx_hash: tx.tx_hash,
+        })
+    }
+
+    /// Polls the transaction `tx_id` every `poll_interval` until it reaches a terminal
+    /// status, returning the completed transaction.
+    ///
+    /// Failed, rejected, blocked and cancelled transactions return
+    /// [`FireblocksError::TransactionNotCompleted`] with the Fireblocks sub-status, and
+    /// transactions still pending after `timeout` [`FireblocksError::WaitTimeout`].
+    pub async fn wait_for_completed(
+        &self,
+        tx_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Transaction, FireblocksError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let tx = self.get_transaction(tx_id).await?;
+            debug!(tx_id = %tx_id, status = ?tx.status, sub_status = %tx.sub_status, "fireblocks transaction status");
+
+            if tx.status == Status::Completed {
+                return Ok(tx);
+            }
+            if tx.status.is_terminal() {
+                return Err(FireblocksError::TransactionNotCompleted {
+                    tx_id: tx_id.to_string(),
+                    status: tx.status,
+                    sub_status: tx.sub_status,
+                });
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(FireblocksError::WaitTimeout {
+                    tx_id: tx_id.to_string(),
+                    status: tx.status,
+                    sub_status: tx.sub_status,
+                    timeout,
+                });
+            }
+            tokio::time::sleep(poll_interval.min(deadline - now)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server};
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TX_HASH: &str = "0x4a6a2ab9c1ab9f0a3cfb0b3b0a8a3e0f5a2bbd86ad8b2a8a8e2c6ef6ac6b0a51";
+
+    fn transaction(status: &str, sub_status: &str, tx_hash: &str) -> Value {
+        json!({
+            "id": "tx-1",
+            "externalTxId": "register-operator-1",
+            "status": status,
+            "subStatus": sub_status,
+            "txHash": tx_hash,
+            "operation": "CONTRACT_CALL",
+            "createdAt": 1718000000000i64,
+            "lastUpdated": 1718000005000i64,
+            "assetId": "ETH",
+            "source": {"type": "VAULT_ACCOUNT", "id": "0", "name": "operator"},
+            "sourceAddress": "",
+            "destination": {"type": "EXTERNAL_WALLET", "id": "c1b5a2e4"},
+            "destinationAddress": "0x53012C69A189cfA2D9d29eb6F19B32e0A2EA3490",
+            "destinationAddressDescription": "",
+            "destinationTag": "",
+            "amountInfo": {"amount": "0", "requestedAmount": "0", "netAmount": "0", "amountUSD": "0"},
+            "feeInfo": {"networkFee": "0.000271", "gasPrice": "1.5"},
+            "feeCurrency": "ETH",
+            "extraParameters": {"contractCallData": "0xdeadbeef"},
+            "numOfConfirmations": 1,
+            "blockInfo": {"blockHeight": "1245063", "blockHash": "0x01"}
+        })
+    }
+
+    async fn mount_status(server: &MockServer, status: &str, sub_status: &str, times: u64) {
+        let tx_hash = if status == "COMPLETED" { TX_HASH } else { "" };
+        Mock::given(method("GET"))
+            .and(path("/v1/transactions/tx-1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(transaction(status, sub_status, tx_hash)),
+            )
+            .up_to_n_times(times)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction() {
+        let server = mock_server().await;
+        mount_status(&server, "BROADCASTING", "", 1).await;
+
+        let client = mock_client(&server);
+        let tx = client.get_transaction("tx-1").await.unwrap();
+
+        assert_eq!(tx.status, Status::Broadcasting);
+        assert_eq!(tx.operation, TransactionOperation::ContractCall);
+        assert_eq!(tx.destination.id, "c1b5a2e4");
+        assert_eq!(tx.block_info.block_height, "1245063");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_completed() {
+        let server = mock_server().await;
+        mount_status(&server, "SUBMITTED", "", 1).await;
+        mount_status(&server, "CONFIRMING", "", 1).await;
+        mount_status(&server, "COMPLETED", "CONFIRMED", 1).await;
+
+        let client = mock_client(&server);
+        let tx_hash = client
+            .wait_for_terminal("tx-1", Duration::from_millis(10), Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert_eq!(tx_hash, TxHash::from_str(TX_HASH).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_failed() {
+        let server = mock_server().await;
+        mount_status(&server, "BROADCASTING", "", 1).await;
+        mount_status(&server, "FAILED", "INSUFFICIENT_FUNDS", 1).await;
+
+        let client = mock_client(&server);
+        let err = client
+            .wait_for_terminal("tx-1", Duration::from_millis(10), Duration::from_secs(5))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FireblocksError::TransactionNotCompleted {
+                status: Status::Failed,
+                ref sub_status,
+                ..
+            } if sub_status == "INSUFFICIENT_FUNDS"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_timeout() {
+        let server = mock_server().await;
+        mount_status(&server, "PENDING_AUTHORIZATION", "", u64::MAX).await;
+
+        let client = mock_client(&server);
+        let err = client
+            .wait_for_terminal("tx-1", Duration::from_millis(10), Duration::from_millis(50))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FireblocksError::WaitTimeout {
+                status: Status::PendingAuthorization,
+                ..
+            }
+        ));
+    }
 }
@@ -6,7 +6,14 @@
 
 pub mod client;
 pub mod contract_call;
-mod get_transaction;
-mod list_contracts;
-mod list_vault_accounts;
+pub mod error;
+pub mod get_asset_addresses;
+pub mod get_transaction;
+pub mod list_contracts;
+pub mod list_vault_accounts;
 pub mod status;
+pub mod typed_message;
+pub mod wallet;
+
+#[cfg(test)]
+mod test_utils;
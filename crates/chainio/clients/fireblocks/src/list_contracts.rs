@@ -1,19 +1,148 @@
-use crate::{client::AssetID, status::Status};
+use crate::{
+    client::{AssetID, FireblocksClient},
+    error::FireblocksError,
+};
 use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
 
-#[allow(unused)]
-#[derive(Debug)]
+/// Status of a whitelisted address that can be used as a transaction destination.
+pub const APPROVED_STATUS: &str = "APPROVED";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Assets {
-    id: AssetID,
-    status: Status,
-    address: Address,
-    tag: String,
+    pub id: AssetID,
+    #[serde(default)]
+    pub status: String,
+    pub address: Address,
+    #[serde(default)]
+    pub tag: String,
 }
 
-#[allow(unused)]
-#[derive(Debug)]
+/// Whitelisted contract or external wallet. Its id is the destination of the
+/// transactions sent to one of its addresses.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct WhitelistedContract {
-    id: String,
-    name: String,
-    assets: Assets,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub assets: Vec<Assets>,
+}
+
+impl WhitelistedContract {
+    /// Whether `address` is whitelisted and approved for `asset_id` in this contract
+    /// wallet
+    pub fn has_asset(&self, asset_id: &AssetID, address: Address) -> bool {
+        self.assets.iter().any(|asset| {
+            &asset.id == asset_id && asset.address == address && asset.status == APPROVED_STATUS
+        })
+    }
+}
+
+impl FireblocksClient {
+    /// List the contract wallets whitelisted in the workspace.
+    pub async fn list_whitelisted_contracts(
+        &self,
+    ) -> Result<Vec<WhitelistedContract>, FireblocksError> {
+        self.get("/v1/contracts").await
+    }
+
+    /// List the external wallets whitelisted in the workspace. Contracts whitelisted
+    /// before contract wallets existed are listed here.
+    pub async fn list_external_contracts(
+        &self,
+    ) -> Result<Vec<WhitelistedContract>, FireblocksError> {
+        self.get("/v1/external_wallets").await
+    }
+
+    /// Whitelisted contract or external wallet holding the approved `asset_id` address
+    /// `address`, if any.
+    pub async fn find_whitelisted_destination(
+        &self,
+        asset_id: &AssetID,
+        address: Address,
+    ) -> Result<Option<WhitelistedContract>, FireblocksError> {
+        let contracts = self.list_whitelisted_contracts().await?;
+        if let Some(contract) = contracts
+            .into_iter()
+            .find(|c| c.has_asset(asset_id, address))
+        {
+            return Ok(Some(contract));
+        }
+
+        let external_contracts = self.list_external_contracts().await?;
+        Ok(external_contracts
+            .into_iter()
+            .find(|c| c.has_asset(asset_id, address)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server};
+    use alloy_primitives::address;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const REGISTRY_COORDINATOR: Address = address!("53012C69A189cfA2D9d29eb6F19B32e0A2EA3490");
+
+    async fn mount_list(server: &MockServer, list_path: &str, body: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(list_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_find_whitelisted_destination_in_external_wallets() {
+        let server = mock_server().await;
+        mount_list(
+            &server,
+            "/v1/contracts",
+            json!([{
+                "id": "c1b5a2e4",
+                "name": "RegistryCoordinator (pending)",
+                "assets": [{"id": "ETH", "status": "WAITING_FOR_APPROVAL", "address": REGISTRY_COORDINATOR}]
+            }]),
+        )
+        .await;
+        mount_list(
+            &server,
+            "/v1/external_wallets",
+            json!([{
+                "id": "e8f1d3c7",
+                "name": "RegistryCoordinator",
+                "customerRefId": "",
+                "assets": [{"id": "ETH", "status": "APPROVED", "address": REGISTRY_COORDINATOR, "tag": ""}]
+            }]),
+        )
+        .await;
+
+        let client = mock_client(&server);
+        let destination = client
+            .find_whitelisted_destination(&AssetID::ETH, REGISTRY_COORDINATOR)
+            .await
+            .unwrap();
+
+        assert_eq!(destination.map(|c| c.id), Some("e8f1d3c7".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_find_whitelisted_destination_not_found() {
+        let server = mock_server().await;
+        mount_list(&server, "/v1/contracts", json!([])).await;
+        mount_list(&server, "/v1/external_wallets", json!([])).await;
+
+        let client = mock_client(&server);
+        let destination = client
+            .find_whitelisted_destination(&AssetID::ETH, REGISTRY_COORDINATOR)
+            .await
+            .unwrap();
+
+        assert!(destination.is_none());
+    }
 }
@@ -1,17 +1,228 @@
-use crate::client::AssetID;
+use crate::{
+    client::{AssetID, FireblocksClient},
+    error::FireblocksError,
+};
+use alloy_primitives::Address;
 use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Asset {
-    id: AssetID,
-    total: String,
-    balance: String,
-    available: String,
+/// Maximum number of vault accounts Fireblocks returns in a single page.
+pub const MAX_VAULT_ACCOUNTS_PAGE_SIZE: u32 = 500;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub id: AssetID,
+    #[serde(default)]
+    pub total: String,
+    #[serde(default)]
+    pub balance: String,
+    #[serde(default)]
+    pub available: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VaultAccount {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+}
+
+impl VaultAccount {
+    /// Whether the vault account holds a wallet for `asset_id`
+    pub fn has_asset(&self, asset_id: &AssetID) -> bool {
+        self.assets.iter().any(|asset| &asset.id == asset_id)
+    }
+}
+
+/// Cursors to the neighbouring pages of a paged listing
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Paging {
+    #[serde(default)]
+    pub before: Option<String>,
+    #[serde(default)]
+    pub after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VaultAccountsPage {
+    pub accounts: Vec<VaultAccount>,
+    #[serde(default)]
+    pub paging: Paging,
+}
+
+impl FireblocksClient {
+    /// One page of at most `limit` vault accounts, starting after the `after` cursor of
+    /// the previous page. Only accounts holding `asset_id` are listed if it is set.
+    pub async fn list_vault_accounts(
+        &self,
+        asset_id: Option<&AssetID>,
+        after: Option<&str>,
+        limit: u32,
+    ) -> Result<VaultAccountsPage, FireblocksError> {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(asset_id) = asset_id {
+            query.append_pair("assetId", &asset_id.to_string());
+        }
+        if let Some(after) = after {
+            query.append_pair("after", after);
+        }
+        query.append_pair(
+            "limit",
+            &limit.min(MAX_VAULT_ACCOUNTS_PAGE_SIZE).to_string(),
+        );
+
+        self.get(&format!("/v1/vault/accounts_paged?{}", query.finish()))
+            .await
+    }
+
+    /// All vault accounts, following the pages of [`FireblocksClient::list_vault_accounts`].
+    pub async fn list_all_vault_accounts(
+        &self,
+        asset_id: Option<&AssetID>,
+    ) -> Result<Vec<VaultAccount>, FireblocksError> {
+        let mut accounts = Vec::new();
+        let mut after = None;
+        loop {
+            let page = self
+                .list_vault_accounts(asset_id, after.as_deref(), MAX_VAULT_ACCOUNTS_PAGE_SIZE)
+                .await?;
+            accounts.extend(page.accounts);
+            match page.paging.after {
+                Some(next) if !next.is_empty() => after = Some(next),
+                _ => return Ok(accounts),
+            }
+        }
+    }
+
+    /// Get the vault account with id `vault_id`.
+    pub async fn get_vault_account(&self, vault_id: &str) -> Result<VaultAccount, FireblocksError> {
+        self.get(&format!("/v1/vault/accounts/{vault_id}")).await
+    }
+
+    /// Vault account owning the `asset_id` deposit address `address`, if any.
+    pub async fn find_vault_account(
+        &self,
+        asset_id: &AssetID,
+        address: Address,
+    ) -> Result<Option<VaultAccount>, FireblocksError> {
+        for account in self.list_all_vault_accounts(Some(asset_id)).await? {
+            let addresses = self.get_asset_addresses(&account.id, asset_id).await?;
+            if addresses.iter().any(|a| a.address == address) {
+                return Ok(Some(account));
+            }
+        }
+        Ok(None)
+    }
 }
 
-#[allow(unused)]
-struct VaultAccount {
-    id: String,
-    name: String,
-    assets: [Asset],
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server};
+    use alloy_primitives::address;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path, query_param, query_param_is_missing},
+        Mock, ResponseTemplate,
+    };
+
+    const OPERATOR: Address = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+
+    fn account(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "name": format!("operator-{id}"),
+            "hiddenOnUI": false,
+            "autoFuel": false,
+            "assets": [{"id": "ETH", "total": "1", "balance": "1", "available": "1", "pending": "0"}]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_all_vault_accounts_follows_pages() {
+        let server = mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts_paged"))
+            .and(query_param("assetId", "ETH"))
+            .and(query_param_is_missing("after"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accounts": [account("0")],
+                "paging": {"after": "MQ=="}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts_paged"))
+            .and(query_param("after", "MQ=="))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accounts": [account("1")],
+                "paging": {}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let accounts = client
+            .list_all_vault_accounts(Some(&AssetID::ETH))
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = accounts.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["0", "1"]);
+        assert!(accounts[0].has_asset(&AssetID::ETH));
+    }
+
+    #[tokio::test]
+    async fn test_get_vault_account() {
+        let server = mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts/3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(account("3")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let account = mock_client(&server).get_vault_account("3").await.unwrap();
+
+        assert_eq!(account.name, "operator-3");
+        assert_eq!(account.assets[0].available, "1");
+    }
+
+    #[tokio::test]
+    async fn test_find_vault_account() {
+        let server = mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts_paged"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accounts": [account("0"), account("1")],
+                "paging": {}
+            })))
+            .mount(&server)
+            .await;
+        for (vault_id, address) in [
+            ("0", address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720")),
+            ("1", OPERATOR),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/v1/vault/accounts/{vault_id}/ETH/addresses")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+                    "assetId": "ETH",
+                    "address": address,
+                    "type": "Permanent"
+                }])))
+                .mount(&server)
+                .await;
+        }
+
+        let client = mock_client(&server);
+        let account = client
+            .find_vault_account(&AssetID::ETH, OPERATOR)
+            .await
+            .unwrap();
+
+        assert_eq!(account.map(|a| a.id), Some("1".to_string()));
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     #[serde(rename = "SUBMITTED")]
     Submitted,
@@ -33,3 +33,17 @@ pub enum Status {
     #[serde(rename = "FAILED")]
     Failed,
 }
+
+impl Status {
+    /// Whether the transaction reached a final status and will not change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Status::Completed
+                | Status::Failed
+                | Status::Rejected
+                | Status::Blocked
+                | Status::Cancelled
+        )
+    }
+}
@@ -0,0 +1,86 @@
+//! Mock Fireblocks server used by the unit tests.
+//!
+//! Every request reaching the server must carry a valid RS256 JWT signed with
+//! `testdata/test_secret_key.pem`, otherwise it is answered with a 401 error response.
+use crate::client::{Claims, FireblocksClient};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+pub(crate) const API_KEY: &str = "test-api-key";
+
+const SECRET_KEY: &[u8] = include_bytes!("../testdata/test_secret_key.pem");
+const PUBLIC_KEY: &[u8] = include_bytes!("../testdata/test_public_key.pem");
+
+/// Matches every request whose JWT does not verify against the test public key.
+struct InvalidJwt;
+
+impl Match for InvalidJwt {
+    fn matches(&self, request: &Request) -> bool {
+        !has_valid_jwt(request)
+    }
+}
+
+fn has_valid_jwt(request: &Request) -> bool {
+    let api_key = request
+        .headers
+        .get("X-API-Key")
+        .and_then(|v| v.to_str().ok());
+    if api_key != Some(API_KEY) {
+        return false;
+    }
+
+    let Some(token) = request
+        .headers
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+    else {
+        return false;
+    };
+
+    let key = DecodingKey::from_rsa_pem(PUBLIC_KEY).expect("invalid test public key");
+    let Ok(token) = jsonwebtoken::decode::<Claims>(token, &key, &Validation::new(Algorithm::RS256))
+    else {
+        return false;
+    };
+    let claims = token.claims;
+
+    let uri = match request.url.query() {
+        Some(query) => format!("{}?{}", request.url.path(), query),
+        None => request.url.path().to_string(),
+    };
+
+    claims.sub == API_KEY
+        && claims.uri == uri
+        && claims.exp > claims.iat
+        && !claims.nonce.is_empty()
+        && claims.body_hash == hex::encode(Sha256::digest(&request.body))
+}
+
+/// Starts a mock Fireblocks server rejecting requests that are not correctly signed.
+pub(crate) async fn mock_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(InvalidJwt)
+        .respond_with(
+            ResponseTemplate::new(401)
+                .set_body_json(json!({"message": "Unauthorized: invalid token", "code": -7})),
+        )
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Client signing with the test secret key and pointing to `server`.
+pub(crate) fn mock_client(server: &MockServer) -> FireblocksClient {
+    FireblocksClient::new(
+        API_KEY.to_string(),
+        SECRET_KEY,
+        server.uri(),
+        Duration::from_secs(5),
+    )
+    .expect("failed to build fireblocks client")
+}
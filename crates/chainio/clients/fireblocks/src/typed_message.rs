@@ -0,0 +1,80 @@
+use crate::client::{AssetID, FireblocksClient};
+use crate::contract_call::{Account, ContractCallResponse, TransactionOperation};
+use crate::error::FireblocksError;
+
+use serde::{Deserialize, Serialize};
+
+/// Message type of EIP-712 typed data
+pub const EIP712_MESSAGE_TYPE: &str = "EIP712";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TypedMessage {
+    /// EIP-712 typed data (`types`, `primaryType`, `domain` and `message`)
+    pub content: serde_json::Value,
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RawMessageData {
+    pub messages: Vec<TypedMessage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TypedMessageParams {
+    #[serde(rename = "rawMessageData")]
+    pub raw_message_data: RawMessageData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TypedMessageRequest {
+    operation: TransactionOperation,
+    #[serde(rename = "externalTxId")]
+    external_tx_id: String,
+    #[serde(rename = "assetId")]
+    asset_id: AssetID,
+    source: Account,
+    note: String,
+    #[serde(rename = "extraParameters")]
+    extra_parameters: TypedMessageParams,
+}
+
+impl TypedMessageRequest {
+    /// New `TYPED_MESSAGE` request signing the EIP-712 `typed_data` with the `asset_id`
+    /// key of the vault account `source_account_id`.
+    pub fn eip712(
+        external_tx_id: String,
+        asset_id: AssetID,
+        source_account_id: String,
+        note: String,
+        typed_data: serde_json::Value,
+    ) -> Self {
+        TypedMessageRequest {
+            operation: TransactionOperation::TypedMessage,
+            external_tx_id,
+            asset_id,
+            source: Account::vault_account(source_account_id),
+            note,
+            extra_parameters: TypedMessageParams {
+                raw_message_data: RawMessageData {
+                    messages: vec![TypedMessage {
+                        content: typed_data,
+                        type_field: EIP712_MESSAGE_TYPE.to_string(),
+                    }],
+                },
+            },
+        }
+    }
+}
+
+impl FireblocksClient {
+    /// Submits a typed message signing request, returning the Fireblocks transaction id
+    /// and its initial status. The signatures are in the `signedMessages` of the
+    /// completed transaction.
+    pub async fn sign_typed_message(
+        &self,
+        req: &TypedMessageRequest,
+    ) -> Result<ContractCallResponse, FireblocksError> {
+        self.post("/v1/transactions", req).await
+    }
+}
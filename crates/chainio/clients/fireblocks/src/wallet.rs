@@ -0,0 +1,808 @@
+use crate::{
+    client::{AssetID, ChainAssets, FireblocksClient},
+    contract_call::ContractCallRequest,
+    error::FireblocksError,
+    list_contracts::WhitelistedContract,
+    status::Status,
+    typed_message::TypedMessageRequest,
+};
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{utils::format_units, Address, Signature, TxHash, TxKind, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::{TransactionReceipt, TransactionRequest};
+use async_trait::async_trait;
+use eigen_client_eth::wallet::{Eip712Signer, TxId, TxSender, WalletError};
+use eigen_utils::get_provider;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::info;
+use uuid::Uuid;
+
+/// Interval between two status polls of a typed message signing request
+const SIGNATURE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Time a typed message signing request may wait for its approvers
+const SIGNATURE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// [`TxSender`] submitting transactions as `CONTRACT_CALL` operations from a Fireblocks
+/// vault account to whitelisted contracts.
+///
+/// Fireblocks assigns the nonce itself. A transaction sent with the same `nonce` as a
+/// previous one that is still pending replaces it through `replaceTxByHash`, which is
+/// how gas price speed-ups are done.
+///
+/// As [`Eip712Signer`], typed data are signed by the vault account key through
+/// `TYPED_MESSAGE` requests.
+#[derive(Debug)]
+pub struct FireblocksWallet {
+    client: FireblocksClient,
+    provider: String,
+    vault_account_id: String,
+    asset_id: AssetID,
+    whitelisted_contracts: Mutex<HashMap<Address, WhitelistedContract>>,
+    nonce_to_tx_id: Mutex<HashMap<u64, String>>,
+    sender_address: Mutex<Option<Address>>,
+}
+
+impl FireblocksWallet {
+    /// New FireblocksWallet sending from vault account `vault_account_id`. The asset is
+    /// looked up in `chain_assets` from the chain id of `provider`, so that transactions
+    /// are never signed with the asset of another network.
+    pub async fn new(
+        client: FireblocksClient,
+        provider: String,
+        vault_account_id: String,
+        chain_assets: &ChainAssets,
+    ) -> Result<Self, FireblocksError> {
+        let chain_id = get_provider(&provider).get_chain_id().await?;
+        let asset_id = chain_assets.asset_id(chain_id)?.clone();
+
+        Ok(Self {
+            client,
+            provider,
+            vault_account_id,
+            asset_id,
+            whitelisted_contracts: Mutex::new(HashMap::new()),
+            nonce_to_tx_id: Mutex::new(HashMap::new()),
+            sender_address: Mutex::new(None),
+        })
+    }
+
+    /// New FireblocksWallet sending from the vault account owning `sender_address`.
+    pub async fn from_sender_address(
+        client: FireblocksClient,
+        provider: String,
+        sender_address: Address,
+        chain_assets: &ChainAssets,
+    ) -> Result<Self, FireblocksError> {
+        let mut wallet = Self::new(client, provider, String::new(), chain_assets).await?;
+        let vault_account = wallet
+            .client
+            .find_vault_account(&wallet.asset_id, sender_address)
+            .await?
+            .ok_or(FireblocksError::VaultAccountNotFound(sender_address))?;
+        wallet.vault_account_id = vault_account.id;
+        *wallet.sender_address.get_mut() = Some(sender_address);
+        Ok(wallet)
+    }
+
+    /// Vault account the transactions are sent from
+    pub fn vault_account_id(&self) -> &str {
+        &self.vault_account_id
+    }
+
+    /// Asset used to pay for the transactions
+    pub fn asset_id(&self) -> &AssetID {
+        &self.asset_id
+    }
+
+    /// Whitelisted contract wallet holding `address` for the wallet asset.
+    pub async fn whitelisted_contract(
+        &self,
+        address: Address,
+    ) -> Result<WhitelistedContract, FireblocksError> {
+        let mut whitelisted_contracts = self.whitelisted_contracts.lock().await;
+        if let Some(contract) = whitelisted_contracts.get(&address) {
+            return Ok(contract.clone());
+        }
+
+        let contract = self
+            .client
+            .find_whitelisted_destination(&self.asset_id, address)
+            .await?
+            .ok_or(FireblocksError::ContractNotWhitelisted(address))?;
+        whitelisted_contracts.insert(address, contract.clone());
+        Ok(contract)
+    }
+
+    async fn fireblocks_sender_address(&self) -> Result<Address, FireblocksError> {
+        let mut sender_address = self.sender_address.lock().await;
+        if let Some(address) = *sender_address {
+            return Ok(address);
+        }
+
+        let addresses = self
+            .client
+            .get_asset_addresses(&self.vault_account_id, &self.asset_id)
+            .await?;
+        let address = addresses.first().map(|a| a.address).ok_or_else(|| {
+            FireblocksError::NoAssetAddress {
+                vault_id: self.vault_account_id.clone(),
+                asset_id: self.asset_id.clone(),
+            }
+        })?;
+        *sender_address = Some(address);
+        Ok(address)
+    }
+
+    /// Hash of the pending transaction previously sent with `nonce`, if any.
+    async fn replaced_tx_hash(&self, nonce: u64) -> Result<Option<String>, FireblocksError> {
+        let Some(tx_id) = self.nonce_to_tx_id.lock().await.get(&nonce).cloned() else {
+            return Ok(None);
+        };
+
+        let tx = self.client.get_transaction(&tx_id).await?;
+        match tx.status {
+            Status::Completed => Err(FireblocksError::TransactionAlreadyCompleted(tx_id)),
+            status if status.is_terminal() => Ok(None),
+            _ if tx.tx_hash.is_empty() => Err(FireblocksError::NotYetBroadcast(tx_id)),
+            _ => Ok(Some(tx.tx_hash)),
+        }
+    }
+
+    async fn fireblocks_send_transaction(
+        &self,
+        tx: TransactionRequest,
+    ) -> Result<String, FireblocksError> {
+        let Some(TxKind::Call(to)) = tx.to else {
+            return Err(FireblocksError::ContractCreationNotSupported);
+        };
+        let contract = self.whitelisted_contract(to).await?;
+
+        let replace_tx_by_hash = match tx.nonce {
+            Some(nonce) => self.replaced_tx_hash(nonce).await?,
+            None => None,
+        };
+
+        let amount = format_units(tx.value.unwrap_or_default(), "ether")?;
+        let calldata = tx.input.input().cloned().unwrap_or_default();
+
+        let mut req = ContractCallRequest::new(
+            Uuid::new_v4().to_string(),
+            self.asset_id.clone(),
+            self.vault_account_id.clone(),
+            contract.id.clone(),
+            amount,
+            calldata.to_string(),
+            replace_tx_by_hash.clone(),
+        );
+        if let Some(gas) = tx.gas {
+            req = req.with_gas_limit(gas.to_string());
+        }
+        if let (Some(max_fee), Some(priority_fee)) =
+            (tx.max_fee_per_gas, tx.max_priority_fee_per_gas)
+        {
+            req = req.with_eip1559_fees(
+                format_units(max_fee, "gwei")?,
+                format_units(priority_fee, "gwei")?,
+            );
+        } else if let Some(gas_price) = tx.gas_price {
+            req = req.with_gas_price(format_units(gas_price, "gwei")?);
+        }
+
+        let response = self.client.contract_call(&req).await?;
+        info!(tx_id = %response.id, contract = %contract.name, to = %to, replaced_tx_hash = ?replace_tx_by_hash, "submitted fireblocks contract call");
+
+        if let Some(nonce) = tx.nonce {
+            self.nonce_to_tx_id
+                .lock()
+                .await
+                .insert(nonce, response.id.clone());
+        }
+        Ok(response.id)
+    }
+
+    async fn fireblocks_transaction_receipt(
+        &self,
+        tx_id: &str,
+    ) -> Result<Option<TransactionReceipt>, FireblocksError> {
+        let tx = self.client.get_transaction(tx_id).await?;
+
+        if !tx.tx_hash.is_empty() {
+            let tx_hash =
+                TxHash::from_str(&tx.tx_hash).map_err(|_| FireblocksError::InvalidTxHash {
+                    tx_id: tx_id.to_string(),
+                    tx_hash: tx.tx_hash.clone(),
+                })?;
+            let receipt = get_provider(&self.provider)
+                .get_transaction_receipt(tx_hash)
+                .await?;
+            if receipt.is_some() {
+                return Ok(receipt);
+            }
+        }
+
+        if tx.status.is_terminal() && tx.status != Status::Completed {
+            return Err(FireblocksError::TransactionNotCompleted {
+                tx_id: tx_id.to_string(),
+                status: tx.status,
+                sub_status: tx.sub_status,
+            });
+        }
+        Ok(None)
+    }
+}
+
+impl FireblocksWallet {
+    async fn fireblocks_sign_typed_data(
+        &self,
+        typed_data: &TypedData,
+    ) -> Result<Signature, FireblocksError> {
+        let signing_hash = typed_data
+            .eip712_signing_hash()
+            .map_err(FireblocksError::InvalidTypedData)?;
+        let req = TypedMessageRequest::eip712(
+            Uuid::new_v4().to_string(),
+            self.asset_id.clone(),
+            self.vault_account_id.clone(),
+            format!("sign {}", typed_data.primary_type),
+            serde_json::to_value(typed_data)?,
+        );
+        let response = self.client.sign_typed_message(&req).await?;
+        info!(tx_id = %response.id, primary_type = %typed_data.primary_type, "submitted fireblocks typed message");
+
+        let tx = self
+            .client
+            .wait_for_completed(&response.id, SIGNATURE_POLL_INTERVAL, SIGNATURE_TIMEOUT)
+            .await?;
+        let message_signature = tx
+            .signed_messages
+            .first()
+            .map(|m| &m.signature)
+            .ok_or_else(|| FireblocksError::NoSignedMessage(tx.id.clone()))?;
+
+        let invalid_signature = |_| FireblocksError::InvalidSignature(tx.id.clone());
+        let r = U256::from_str_radix(message_signature.r.trim_start_matches("0x"), 16)
+            .map_err(invalid_signature)?;
+        let s = U256::from_str_radix(message_signature.s.trim_start_matches("0x"), 16)
+            .map_err(invalid_signature)?;
+        let signature = Signature::from_rs_and_parity(r, s, message_signature.v)
+            .map_err(|_| FireblocksError::InvalidSignature(tx.id.clone()))?;
+
+        let expected = self.fireblocks_sender_address().await?;
+        let recovered = signature
+            .recover_address_from_prehash(&signing_hash)
+            .map_err(|_| FireblocksError::InvalidSignature(tx.id.clone()))?;
+        if recovered != expected {
+            return Err(FireblocksError::UnexpectedSigner {
+                tx_id: tx.id,
+                expected,
+                recovered,
+            });
+        }
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Eip712Signer for FireblocksWallet {
+    async fn signer_address(&self) -> Result<Address, WalletError> {
+        TxSender::sender_address(self).await
+    }
+
+    async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, WalletError> {
+        self.fireblocks_sign_typed_data(typed_data)
+            .await
+            .map_err(|e| WalletError::SignTypedData(e.into()))
+    }
+}
+
+#[async_trait]
+impl TxSender for FireblocksWallet {
+    async fn sender_address(&self) -> Result<Address, WalletError> {
+        self.fireblocks_sender_address()
+            .await
+            .map_err(|e| WalletError::SenderAddress(e.into()))
+    }
+
+    async fn send_transaction(&self, tx: TransactionRequest) -> Result<TxId, WalletError> {
+        self.fireblocks_send_transaction(tx)
+            .await
+            .map_err(|e| WalletError::SendTransaction(e.into()))
+    }
+
+    async fn get_transaction_receipt(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<Option<TransactionReceipt>, WalletError> {
+        match self.fireblocks_transaction_receipt(tx_id).await {
+            Ok(receipt) => Ok(receipt),
+            Err(e @ FireblocksError::TransactionNotCompleted { .. }) => {
+                Err(WalletError::TransactionFailed {
+                    tx_id: tx_id.clone(),
+                    reason: e.to_string(),
+                })
+            }
+            Err(e) => Err(WalletError::GetTransactionReceipt {
+                tx_id: tx_id.clone(),
+                source: e.into(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_server};
+    use alloy_primitives::{address, bytes};
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use eigen_testing_utils::mock_rpc::mock_rpc;
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{body_partial_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const REGISTRY_COORDINATOR: Address = address!("53012C69A189cfA2D9d29eb6F19B32e0A2EA3490");
+    const OPERATOR: Address = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+    const TX_HASH: &str = "0x4a6a2ab9c1ab9f0a3cfb0b3b0a8a3e0f5a2bbd86ad8b2a8a8e2c6ef6ac6b0a51";
+
+    fn receipt() -> Value {
+        json!({
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "transactionHash": TX_HASH,
+            "transactionIndex": "0x0",
+            "blockHash": "0x0a5d0a3a0d4ae7b7b4f1f0a3c1c3f5a3d0c9e3b1f9c5d1e2b3a4c5d6e7f80910",
+            "blockNumber": "0x12ffe7",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "from": OPERATOR,
+            "to": REGISTRY_COORDINATOR,
+            "contractAddress": null
+        })
+    }
+
+    fn transaction(status: &str, sub_status: &str, tx_hash: &str) -> Value {
+        json!({
+            "id": "tx-1",
+            "status": status,
+            "subStatus": sub_status,
+            "txHash": tx_hash,
+            "operation": "CONTRACT_CALL",
+            "assetId": "ETH",
+            "source": {"type": "VAULT_ACCOUNT", "id": "0"},
+            "destination": {"type": "EXTERNAL_WALLET", "id": "c1b5a2e4"},
+        })
+    }
+
+    async fn mount_contracts(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/v1/contracts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+                "id": "c1b5a2e4",
+                "name": "RegistryCoordinator",
+                "assets": [{
+                    "id": "ETH",
+                    "status": "APPROVED",
+                    "address": REGISTRY_COORDINATOR,
+                    "tag": ""
+                }]
+            }])))
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/external_wallets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_transaction(server: &MockServer, status: &str, sub_status: &str, hash: &str) {
+        Mock::given(method("GET"))
+            .and(path("/v1/transactions/tx-1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(transaction(status, sub_status, hash)),
+            )
+            .mount(server)
+            .await;
+    }
+
+    async fn build_wallet(server: &MockServer, rpc: &MockServer) -> FireblocksWallet {
+        FireblocksWallet::new(
+            mock_client(server),
+            rpc.uri(),
+            "0".to_string(),
+            &ChainAssets::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn register_tx() -> TransactionRequest {
+        TransactionRequest::default()
+            .to(REGISTRY_COORDINATOR)
+            .input(bytes!("deadbeef").into())
+            .gas_limit(2000000)
+            .max_fee_per_gas(30_000_000_000)
+            .max_priority_fee_per_gas(1_500_000_000)
+    }
+
+    #[tokio::test]
+    async fn test_new_unsupported_chain() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x4d2")))]).await;
+
+        let err = FireblocksWallet::new(
+            mock_client(&server),
+            rpc.uri(),
+            "0".to_string(),
+            &ChainAssets::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, FireblocksError::UnsupportedChain(1234)));
+    }
+
+    #[tokio::test]
+    async fn test_new_custom_asset() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x4268")))]).await;
+        let chain_assets = ChainAssets::default().with_asset(17000, "HOLESKY_ETH_CUSTOM");
+
+        let wallet = FireblocksWallet::new(
+            mock_client(&server),
+            rpc.uri(),
+            "0".to_string(),
+            &chain_assets,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            wallet.asset_id(),
+            &AssetID::Custom("HOLESKY_ETH_CUSTOM".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sender_address() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts/0/ETH/addresses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+                "assetId": "ETH",
+                "address": OPERATOR,
+                "description": "",
+                "tag": "",
+                "type": "Permanent"
+            }])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+
+        assert_eq!(wallet.sender_address().await.unwrap(), OPERATOR);
+        // cached
+        assert_eq!(wallet.sender_address().await.unwrap(), OPERATOR);
+    }
+
+    #[tokio::test]
+    async fn test_from_sender_address() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts_paged"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accounts": [{"id": "4", "name": "operator", "assets": [{"id": "ETH"}]}],
+                "paging": {}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts/4/ETH/addresses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{"assetId": "ETH", "address": OPERATOR}])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let wallet = FireblocksWallet::from_sender_address(
+            mock_client(&server),
+            rpc.uri(),
+            OPERATOR,
+            &ChainAssets::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(wallet.vault_account_id(), "4");
+        assert_eq!(wallet.sender_address().await.unwrap(), OPERATOR);
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        mount_contracts(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .and(body_partial_json(json!({
+                "operation": "CONTRACT_CALL",
+                "assetId": "ETH",
+                "source": {"type": "VAULT_ACCOUNT", "id": "0"},
+                "destination": {"type": "EXTERNAL_WALLET", "id": "c1b5a2e4"},
+                "amount": "0.000000000000000000",
+                "extraParameters": {"contractCallData": "0xdeadbeef"},
+                "gasLimit": "2000000",
+                "maxFee": "30.000000000",
+                "priorityFee": "1.500000000"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"id": "tx-1", "status": "SUBMITTED"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let tx_id = wallet.send_transaction(register_tx()).await.unwrap();
+
+        assert_eq!(tx_id, "tx-1");
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction_not_whitelisted() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        mount_contracts(&server).await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let tx = TransactionRequest::default()
+            .to(OPERATOR)
+            .value(U256::from(1));
+        let err = wallet.fireblocks_send_transaction(tx).await.unwrap_err();
+
+        assert!(matches!(err, FireblocksError::ContractNotWhitelisted(a) if a == OPERATOR));
+    }
+
+    #[tokio::test]
+    async fn test_speed_up_replaces_tx_by_hash() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        mount_contracts(&server).await;
+        mount_transaction(&server, "BROADCASTING", "", TX_HASH).await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .and(body_partial_json(
+                json!({"replaceTxByHash": TX_HASH, "maxFee": "60.000000000"}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"id": "tx-2", "status": "SUBMITTED"})),
+            )
+            .expect(1)
+            .with_priority(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"id": "tx-1", "status": "SUBMITTED"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let tx_id = wallet
+            .send_transaction(register_tx().nonce(7))
+            .await
+            .unwrap();
+        assert_eq!(tx_id, "tx-1");
+
+        let sped_up = register_tx().nonce(7).max_fee_per_gas(60_000_000_000);
+        let tx_id = wallet.send_transaction(sped_up).await.unwrap();
+        assert_eq!(tx_id, "tx-2");
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_receipt() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![
+            ("eth_chainId", Ok(json!("0x1"))),
+            ("eth_getTransactionReceipt", Ok(receipt())),
+        ])
+        .await;
+        mount_transaction(&server, "COMPLETED", "CONFIRMED", TX_HASH).await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let receipt = wallet
+            .get_transaction_receipt(&"tx-1".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(receipt.transaction_hash, TxHash::from_str(TX_HASH).unwrap());
+        assert!(receipt.status());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_receipt_pending() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        mount_transaction(&server, "PENDING_SIGNATURE", "", "").await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let receipt = wallet
+            .get_transaction_receipt(&"tx-1".to_string())
+            .await
+            .unwrap();
+
+        assert!(receipt.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_receipt_rejected() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        mount_transaction(&server, "REJECTED", "REJECTED_BY_USER", "").await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let err = wallet
+            .get_transaction_receipt(&"tx-1".to_string())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(err, WalletError::TransactionFailed { ref reason, .. } if reason.contains("REJECTED_BY_USER"))
+        );
+    }
+
+    fn registration_typed_data() -> TypedData {
+        serde_json::from_value(json!({
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"}
+                ],
+                "OperatorAVSRegistration": [
+                    {"name": "operator", "type": "address"},
+                    {"name": "avs", "type": "address"},
+                    {"name": "salt", "type": "bytes32"},
+                    {"name": "expiry", "type": "uint256"}
+                ]
+            },
+            "primaryType": "OperatorAVSRegistration",
+            "domain": {
+                "name": "EigenLayer",
+                "chainId": 1,
+                "verifyingContract": "0x135dda560e946695d6f155dacafc6f1f25c1f5af"
+            },
+            "message": {
+                "operator": OPERATOR,
+                "avs": REGISTRY_COORDINATOR,
+                "salt": format!("0x{}", "02".repeat(32)),
+                "expiry": 1000000000
+            }
+        }))
+        .unwrap()
+    }
+
+    /// Mocks Fireblocks signing `typed_data` with `vault_key` for the vault account
+    /// holding `vault_address`.
+    async fn mount_typed_message(
+        server: &MockServer,
+        typed_data: &TypedData,
+        vault_key: &PrivateKeySigner,
+        vault_address: Address,
+    ) {
+        let signature = vault_key
+            .sign_hash_sync(&typed_data.eip712_signing_hash().unwrap())
+            .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/v1/vault/accounts/0/ETH/addresses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{"assetId": "ETH", "address": vault_address}])),
+            )
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .and(body_partial_json(json!({
+                "operation": "TYPED_MESSAGE",
+                "assetId": "ETH",
+                "source": {"type": "VAULT_ACCOUNT", "id": "0"},
+                "extraParameters": {"rawMessageData": {"messages": [{
+                    "type": "EIP712",
+                    "content": {"primaryType": "OperatorAVSRegistration"}
+                }]}}
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"id": "tx-sig", "status": "SUBMITTED"})),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/transactions/tx-sig"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "tx-sig",
+                "status": "COMPLETED",
+                "subStatus": "",
+                "txHash": "",
+                "operation": "TYPED_MESSAGE",
+                "assetId": "ETH",
+                "source": {"type": "VAULT_ACCOUNT", "id": "0"},
+                "signedMessages": [{
+                    "content": "",
+                    "algorithm": "MPC_ECDSA_SECP256K1",
+                    "publicKey": "",
+                    "signature": {
+                        "fullSig": format!("{:064x}{:064x}", signature.r(), signature.s()),
+                        "r": format!("{:064x}", signature.r()),
+                        "s": format!("{:064x}", signature.s()),
+                        "v": signature.v().y_parity_byte()
+                    }
+                }]
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_sign_typed_data() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        let vault_key = PrivateKeySigner::random();
+        let typed_data = registration_typed_data();
+        mount_typed_message(&server, &typed_data, &vault_key, vault_key.address()).await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let signature = wallet.sign_typed_data(&typed_data).await.unwrap();
+
+        let recovered = signature
+            .recover_address_from_prehash(&typed_data.eip712_signing_hash().unwrap())
+            .unwrap();
+        assert_eq!(recovered, vault_key.address());
+    }
+
+    #[tokio::test]
+    async fn test_sign_typed_data_unexpected_signer() {
+        let server = mock_server().await;
+        let rpc = mock_rpc(vec![("eth_chainId", Ok(json!("0x1")))]).await;
+        let vault_key = PrivateKeySigner::random();
+        let typed_data = registration_typed_data();
+        mount_typed_message(&server, &typed_data, &vault_key, OPERATOR).await;
+
+        let wallet = build_wallet(&server, &rpc).await;
+        let err = wallet
+            .fireblocks_sign_typed_data(&typed_data)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FireblocksError::UnexpectedSigner { expected, recovered, .. }
+                if expected == OPERATOR && recovered == vault_key.address()
+        ));
+    }
+}
@@ -1,10 +0,0 @@
-
-#![doc(
-    html_logo_url = "https://github.com/supernovahs/eigensdk-rs/assets/91280922/bd13caec-3c00-4afc-839a-b83d2890beb5",
-    issue_tracker_base_url = "https://github.com/supernovahs/eigen-rs/issues/"
-)]
-#![cfg_attr(not(test), warn(unused_crate_dependencies))]
-
-mod fireblocks;
-
-mod wallet;
\ No newline at end of file
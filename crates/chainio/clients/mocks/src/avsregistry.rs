@@ -0,0 +1,276 @@
+// The mocked signatures mirror the reader trait, operator sets included.
+#![allow(clippy::type_complexity)]
+
+use alloy_primitives::{Address, Bytes, FixedBytes, B256, U256};
+use alloy_rpc_types::Filter;
+use async_trait::async_trait;
+use eigen_client_avsregistry::{
+    error::AvsRegistryError, reader::AvsRegistryReader, subscriber::AvsRegistrySubscriber,
+    writer::AvsRegistryWriter,
+};
+use eigen_client_eth::outcome::TxOutcome;
+use eigen_crypto_bls::attestation::KeyPair;
+use eigen_types::{
+    operator::{
+        OperatorPubKeys, OperatorRegistrationEvent, OperatorStake, OperatorStakeUpdateEvent,
+        QuorumOperatorSet, StakeUpdate,
+    },
+    quorum::QuorumConfig,
+};
+use eigen_utils::binding::{
+    OperatorStateRetriever,
+    RegistryCoordinator::{OperatorKickParam, SignatureWithSaltAndExpiry},
+};
+use futures_util::stream::BoxStream;
+use mockall::mock;
+use std::collections::HashMap;
+
+mock! {
+    /// Mock [`AvsRegistryReader`]
+    #[derive(Debug)]
+    pub AvsRegistryReader {}
+
+    #[async_trait]
+    impl AvsRegistryReader for AvsRegistryReader {
+        async fn get_quorum_count(
+            &self,
+        ) -> Result<u8, AvsRegistryError>;
+
+        async fn get_quorum_config(
+            &self,
+            quorum_number: u8,
+        ) -> Result<QuorumConfig, AvsRegistryError>;
+
+        async fn get_quorum_configs(
+            &self,
+        ) -> Result<Vec<QuorumConfig>, AvsRegistryError>;
+
+        async fn get_operators_stake_in_quorums_at_block(
+            &self,
+            block_number: u32,
+            quorum_numbers: Bytes,
+        ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+        async fn get_operators_stake_in_quorums_at_block_operator_id(
+            &self,
+            block_number: u32,
+            operator_id: B256,
+        ) -> Result<(U256, QuorumOperatorSet), AvsRegistryError>;
+
+        async fn get_operators_stake_in_quorums_at_current_block(
+            &self,
+            quorum_numbers: Bytes,
+        ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+        async fn get_operators_stake_in_quorums_of_operator_at_block(
+            &self,
+            operator_id: B256,
+            block_number: u32,
+        ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+        async fn get_operators_stake_in_quorums_of_operator_at_current_block(
+            &self,
+            operator_id: B256,
+        ) -> Result<QuorumOperatorSet, AvsRegistryError>;
+
+        async fn get_operator_stake_in_quorums_of_operator_at_current_block(
+            &self,
+            operator_id: B256,
+        ) -> Result<Vec<OperatorStake>, AvsRegistryError>;
+
+        async fn get_stake_history(
+            &self,
+            operator_id: B256,
+            quorum_number: u8,
+        ) -> Result<Vec<StakeUpdate>, AvsRegistryError>;
+
+        async fn get_total_stake_history(
+            &self,
+            quorum_number: u8,
+        ) -> Result<Vec<StakeUpdate>, AvsRegistryError>;
+
+        async fn get_stake_at_block_number_and_index(
+            &self,
+            quorum_number: u8,
+            block_number: u32,
+            operator_id: B256,
+            index: U256,
+        ) -> Result<U256, AvsRegistryError>;
+
+        async fn get_check_signatures_indices(
+            &self,
+            reference_block_number: u32,
+            quorum_numbers: Vec<u8>,
+            non_signer_operator_ids: Vec<FixedBytes<32>>,
+        ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError>;
+
+        async fn get_operator_id(
+            &self,
+            operator_address: Address,
+        ) -> Result<FixedBytes<32>, AvsRegistryError>;
+
+        async fn get_operator_from_id(
+            &self,
+            operator_id: [u8; 32],
+        ) -> Result<Address, AvsRegistryError>;
+
+        async fn is_operator_registered(
+            &self,
+            operator_address: Address,
+        ) -> Result<bool, AvsRegistryError>;
+
+        async fn query_existing_registered_operator_pub_keys(
+            &self,
+            start_block: u64,
+            stop_block: u64,
+        ) -> Result<(Vec<Address>, Vec<OperatorPubKeys>), AvsRegistryError>;
+
+        async fn query_existing_registered_operator_sockets(
+            &self,
+            start_block: u64,
+            stop_block: u64,
+        ) -> Result<HashMap<FixedBytes<32>, String>, AvsRegistryError>;
+
+        async fn query_operator_registration_events(
+            &self,
+            start_block: u64,
+            stop_block: u64,
+        ) -> Result<Vec<OperatorRegistrationEvent>, AvsRegistryError>;
+
+        async fn query_operator_stake_update_events(
+            &self,
+            start_block: u64,
+            stop_block: u64,
+        ) -> Result<Vec<OperatorStakeUpdateEvent>, AvsRegistryError>;
+    }
+}
+
+mock! {
+    /// Mock [`AvsRegistryWriter`]
+    #[derive(Debug)]
+    pub AvsRegistryWriter {}
+
+    #[async_trait]
+    impl AvsRegistryWriter for AvsRegistryWriter {
+        async fn register_operator_in_quorum_with_avs_registry_coordinator(
+            &self,
+            bls_key_pair: KeyPair,
+            operator_to_avs_registration_sig_salt: FixedBytes<32>,
+            operator_to_avs_registration_sig_expiry: U256,
+            quorum_numbers: Bytes,
+            socket: String,
+        ) -> Result<TxOutcome, AvsRegistryError>;
+
+        #[allow(clippy::too_many_arguments)]
+        async fn register_operator_with_churn(
+            &self,
+            bls_key_pair: KeyPair,
+            operator_to_avs_registration_sig_salt: FixedBytes<32>,
+            operator_to_avs_registration_sig_expiry: U256,
+            quorum_numbers: Bytes,
+            socket: String,
+            operator_kick_params: Vec<OperatorKickParam>,
+            churn_approver_signature: SignatureWithSaltAndExpiry,
+        ) -> Result<TxOutcome, AvsRegistryError>;
+
+        async fn update_stakes_of_entire_operator_set_for_quorums(
+            &self,
+            operators_per_quorum: Vec<Vec<Address>>,
+            quorum_number: Bytes,
+        ) -> Result<TxOutcome, AvsRegistryError>;
+
+        async fn update_stakes_of_operator_subset_for_all_quorums(
+            &self,
+            operators: Vec<Address>,
+        ) -> Result<TxOutcome, AvsRegistryError>;
+
+        async fn deregister_operator(
+            &self,
+            quorum_numbers: Bytes,
+        ) -> Result<TxOutcome, AvsRegistryError>;
+
+        async fn update_socket(&self, socket: String) -> Result<TxOutcome, AvsRegistryError>;
+    }
+}
+
+mock! {
+    /// Mock [`AvsRegistrySubscriber`]
+    #[derive(Debug)]
+    pub AvsRegistrySubscriber {}
+
+    #[async_trait]
+    impl AvsRegistrySubscriber for AvsRegistrySubscriber {
+        async fn get_new_pub_key_registration_filter(
+            &self,
+        ) -> Result<Filter, AvsRegistryError>;
+
+        async fn subscribe_operator_registration_events(
+            &self,
+            registry_coordinator_addr: Address,
+        ) -> Result<BoxStream<'static, OperatorRegistrationEvent>, AvsRegistryError>;
+
+        async fn subscribe_operator_stake_update_events(
+            &self,
+            stake_registry_addr: Address,
+        ) -> Result<BoxStream<'static, OperatorStakeUpdateEvent>, AvsRegistryError>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use mockall::predicate::eq;
+
+    async fn total_stake(
+        reader: &dyn AvsRegistryReader,
+        block_number: u32,
+        quorum_numbers: Bytes,
+    ) -> U256 {
+        let operator_set = reader
+            .get_operators_stake_in_quorums_at_block(block_number, quorum_numbers)
+            .await
+            .unwrap();
+        operator_set
+            .quorums()
+            .map(|quorum| operator_set.total_stake(quorum))
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_mock_operators_stake_in_quorums_at_block() {
+        let operator = |id: u8, stake: u128| OperatorStateRetriever::Operator {
+            operator: address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"),
+            operatorId: FixedBytes::from([id; 32]),
+            stake,
+        };
+        let mut reader = MockAvsRegistryReader::new();
+        reader
+            .expect_get_operators_stake_in_quorums_at_block()
+            .with(eq(100), eq(Bytes::from(vec![0, 1])))
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QuorumOperatorSet::from_operator_state(
+                    &[0, 1],
+                    vec![vec![operator(1, 10)], vec![operator(2, 20)]],
+                ))
+            });
+
+        assert_eq!(
+            total_stake(&reader, 100, Bytes::from(vec![0, 1])).await,
+            U256::from(30)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mock_reader_error() {
+        let mut reader = MockAvsRegistryReader::new();
+        reader
+            .expect_get_quorum_count()
+            .returning(|| Err(AvsRegistryError::Paused));
+
+        let err = reader.get_quorum_count().await.unwrap_err();
+
+        assert!(matches!(err, AvsRegistryError::Paused));
+    }
+}
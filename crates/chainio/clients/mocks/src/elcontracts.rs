@@ -0,0 +1,116 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, FixedBytes, U256};
+use async_trait::async_trait;
+use eigen_client_elcontracts::{error::ElContractsError, reader::ELReader, writer::ELWriter};
+use eigen_client_eth::outcome::TxOutcome;
+use eigen_types::operator::Operator;
+use eigen_utils::binding::DelegationManager::SignatureWithExpiry;
+use mockall::mock;
+
+mock! {
+    /// Mock [`ELReader`]
+    #[derive(Debug)]
+    pub ELReader {}
+
+    #[async_trait]
+    impl ELReader for ELReader {
+        async fn calculate_delegation_approval_digest_hash(
+            &self,
+            staker: Address,
+            operator: Address,
+            delegation_approver: Address,
+            approve_salt: FixedBytes<32>,
+            expiry: U256,
+        ) -> Result<FixedBytes<32>, ElContractsError>;
+
+        async fn calculate_operator_avs_registration_digest_hash(
+            &self,
+            operator: Address,
+            avs: Address,
+            salt: FixedBytes<32>,
+            expiry: U256,
+        ) -> Result<FixedBytes<32>, ElContractsError>;
+
+        async fn delegation_approval_typed_data(
+            &self,
+            staker: Address,
+            operator: Address,
+            delegation_approver: Address,
+            approve_salt: FixedBytes<32>,
+            expiry: U256,
+        ) -> Result<TypedData, ElContractsError>;
+
+        async fn operator_avs_registration_typed_data(
+            &self,
+            operator: Address,
+            avs: Address,
+            salt: FixedBytes<32>,
+            expiry: U256,
+        ) -> Result<TypedData, ElContractsError>;
+
+        async fn get_operator_shares_in_strategy(
+            &self,
+            operator_addr: Address,
+            strategy_addr: Address,
+        ) -> Result<U256, ElContractsError>;
+
+        async fn operator_is_frozen(
+            &self,
+            operator_addr: Address,
+        ) -> Result<bool, ElContractsError>;
+
+        async fn service_manager_can_slash_operator_until_block(
+            &self,
+            operator_addr: Address,
+            service_manager_addr: Address,
+        ) -> Result<u32, ElContractsError>;
+
+        async fn get_strategy_and_underlying_erc20_token(
+            &self,
+            strategy_addr: Address,
+        ) -> Result<(Address, Address, Address), ElContractsError>;
+
+        async fn get_operator_details(
+            &self,
+            operator: Address,
+        ) -> Result<Operator, ElContractsError>;
+
+        async fn is_operator_registered(
+            &self,
+            operator: Address,
+        ) -> Result<bool, ElContractsError>;
+    }
+}
+
+mock! {
+    /// Mock [`ELWriter`]
+    #[derive(Debug)]
+    pub ELWriter {}
+
+    #[async_trait]
+    impl ELWriter for ELWriter {
+        async fn register_as_operator(
+            &self,
+            operator: Operator,
+        ) -> Result<TxOutcome, ElContractsError>;
+
+        async fn update_operator_details(
+            &self,
+            operator: Operator,
+        ) -> Result<(TxOutcome, TxOutcome), ElContractsError>;
+
+        async fn sign_delegation_approval(
+            &self,
+            staker: Address,
+            operator: Address,
+            approve_salt: FixedBytes<32>,
+            expiry: U256,
+        ) -> Result<SignatureWithExpiry, ElContractsError>;
+
+        async fn deposit_erc20_into_strategy(
+            &self,
+            strategy_addr: Address,
+            amount: U256,
+        ) -> Result<TxOutcome, ElContractsError>;
+    }
+}
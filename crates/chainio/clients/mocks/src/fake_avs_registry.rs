@@ -0,0 +1,881 @@
+use alloy_primitives::{Address, Bytes, FixedBytes, TxHash, B256, U256};
+use async_trait::async_trait;
+use eigen_client_avsregistry::{error::AvsRegistryError, reader::AvsRegistryReader};
+use eigen_types::{
+    operator::{
+        OperatorPubKeys, OperatorRegistrationEvent, OperatorStake, OperatorStakeUpdateEvent,
+        QuorumOperatorSet, RegistrationKind, StakeUpdate,
+    },
+    quorum::QuorumConfig,
+};
+use eigen_utils::binding::{
+    BLSApkRegistry::{G1Point, G2Point},
+    OperatorStateRetriever,
+};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::RwLock;
+
+/// Operator registered in a [`FakeAvsRegistry`]
+#[derive(Debug, Clone)]
+pub struct FakeOperator {
+    /// Address of the operator
+    pub address: Address,
+    /// Id of the operator, i.e. the hash of its G1 pubkey
+    pub operator_id: B256,
+    /// BLS pubkeys of the operator
+    pub pub_keys: OperatorPubKeys,
+    /// Socket of the operator
+    pub socket: String,
+}
+
+impl FakeOperator {
+    /// Operator with id `[id; 32]`, the G1 generator as G1 pubkey, a zero G2 pubkey and
+    /// socket `operator-{id}:8080`
+    pub fn new(id: u8, address: Address) -> Self {
+        Self {
+            address,
+            operator_id: FixedBytes::from([id; 32]),
+            pub_keys: OperatorPubKeys {
+                g1_pub_key: G1Point {
+                    X: U256::from(1),
+                    Y: U256::from(2),
+                },
+                g2_pub_key: G2Point {
+                    X: [U256::ZERO; 2],
+                    Y: [U256::ZERO; 2],
+                },
+            },
+            socket: format!("operator-{id}:8080"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Registration {
+    operator: FakeOperator,
+    quorums: Vec<u8>,
+    registered_at: u32,
+    deregistered_at: Option<u32>,
+    /// Stake updates of each quorum as `(block, stake)`, in block order
+    stakes: HashMap<u8, Vec<(u32, u128)>>,
+    /// Socket updates as `(block, socket)`, in block order, starting with the socket
+    /// set at registration
+    sockets: Vec<(u32, String)>,
+}
+
+impl Registration {
+    fn is_registered_at(&self, block: u32) -> bool {
+        self.registered_at <= block && self.deregistered_at.map_or(true, |b| block < b)
+    }
+
+    fn stake_at(&self, quorum: u8, block: u32) -> u128 {
+        self.stakes
+            .get(&quorum)
+            .and_then(|updates| updates.iter().rev().find(|(b, _)| *b <= block))
+            .map_or(0, |(_, stake)| *stake)
+    }
+
+    fn operator_stake(&self, quorum: u8, block: u32) -> OperatorStake {
+        OperatorStake {
+            operator: self.operator.address,
+            operator_id: self.operator.operator_id,
+            quorum,
+            stake: U256::from(self.stake_at(quorum, block)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    current_block: u32,
+    quorum_count: u8,
+    /// Registrations in registration order
+    registrations: Vec<Registration>,
+    check_signatures_indices: HashMap<u32, OperatorStateRetriever::CheckSignaturesIndices>,
+    quorum_configs: HashMap<u8, QuorumConfig>,
+}
+
+impl FakeState {
+    fn registration_at(&self, operator_id: B256, block: u32) -> Option<&Registration> {
+        self.registrations
+            .iter()
+            .find(|r| r.operator.operator_id == operator_id && r.is_registered_at(block))
+    }
+
+    fn current_registration_mut(&mut self, operator_id: B256) -> &mut Registration {
+        let block = self.current_block;
+        self.registrations
+            .iter_mut()
+            .find(|r| r.operator.operator_id == operator_id && r.is_registered_at(block))
+            .unwrap_or_else(|| panic!("operator {operator_id} is not registered"))
+    }
+
+    fn operators_at(
+        &self,
+        block: u32,
+        quorum_numbers: &[u8],
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        let mut operator_set = QuorumOperatorSet::default();
+        for quorum in quorum_numbers {
+            if *quorum >= self.quorum_count {
+                return Err(AvsRegistryError::QuorumDoesNotExist);
+            }
+            let operators = self
+                .registrations
+                .iter()
+                .filter(|r| r.is_registered_at(block) && r.quorums.contains(quorum))
+                .map(|r| r.operator_stake(*quorum, block))
+                .collect();
+            operator_set.insert(*quorum, operators);
+        }
+        Ok(operator_set)
+    }
+
+    /// Stake changes of `operator_id` in `quorum` as `(block, stake)`, in block order,
+    /// with a zero stake at each deregistration
+    fn stake_changes(&self, operator_id: B256, quorum: u8) -> Vec<(u32, u128)> {
+        let mut changes = Vec::new();
+        for registration in self
+            .registrations
+            .iter()
+            .filter(|r| r.operator.operator_id == operator_id && r.quorums.contains(&quorum))
+        {
+            changes.extend(registration.stakes.get(&quorum).into_iter().flatten());
+            if let Some(block) = registration.deregistered_at {
+                changes.push((block, 0));
+            }
+        }
+        changes
+    }
+
+    /// Total stake changes of `quorum` as `(block, stake)`, in block order
+    fn total_stake_changes(&self, quorum: u8) -> Vec<(u32, u128)> {
+        let mut blocks: Vec<u32> = self
+            .registrations
+            .iter()
+            .filter(|r| r.quorums.contains(&quorum))
+            .flat_map(|r| {
+                let updates = r.stakes.get(&quorum).into_iter().flatten();
+                updates.map(|(block, _)| *block).chain(r.deregistered_at)
+            })
+            .collect();
+        blocks.sort_unstable();
+        blocks.dedup();
+        blocks
+            .into_iter()
+            .map(|block| {
+                let total = self
+                    .registrations
+                    .iter()
+                    .filter(|r| r.is_registered_at(block) && r.quorums.contains(&quorum))
+                    .map(|r| r.stake_at(quorum, block))
+                    .sum();
+                (block, total)
+            })
+            .collect()
+    }
+
+    /// Blocks from `start_block` to `stop_block`, up to the current block if it is 0
+    fn block_range(&self, start_block: u64, stop_block: u64) -> RangeInclusive<u64> {
+        let stop_block = if stop_block == 0 {
+            self.current_block.into()
+        } else {
+            stop_block
+        };
+        start_block..=stop_block
+    }
+
+    fn quorum_config(&self, quorum_number: u8) -> Result<QuorumConfig, AvsRegistryError> {
+        if quorum_number >= self.quorum_count {
+            return Err(AvsRegistryError::QuorumDoesNotExist);
+        }
+        Ok(self
+            .quorum_configs
+            .get(&quorum_number)
+            .cloned()
+            .unwrap_or_else(|| QuorumConfig {
+                quorum_number,
+                ..Default::default()
+            }))
+    }
+
+    fn operator_quorums_at(
+        &self,
+        operator_id: B256,
+        block: u32,
+    ) -> Result<Vec<u8>, AvsRegistryError> {
+        self.registration_at(operator_id, block)
+            .map(|r| r.quorums.clone())
+            .ok_or(AvsRegistryError::OperatorNotRegistered)
+    }
+}
+
+/// Stake updates of the `(block, stake)` changes, the last change of a block replacing
+/// the previous ones as in the StakeRegistry
+fn stake_history(changes: Vec<(u32, u128)>) -> Vec<StakeUpdate> {
+    let mut history: Vec<StakeUpdate> = Vec::with_capacity(changes.len());
+    for (block, stake) in changes {
+        match history.last_mut() {
+            Some(last) if last.update_block_number == block => last.stake = U256::from(stake),
+            last => {
+                if let Some(last) = last {
+                    last.next_update_block_number = Some(block);
+                }
+                history.push(StakeUpdate {
+                    update_block_number: block,
+                    next_update_block_number: None,
+                    stake: U256::from(stake),
+                });
+            }
+        }
+    }
+    history
+}
+
+/// In-memory AVS registry implementing [`AvsRegistryReader`], for tests that need
+/// consistent registry state without a node.
+///
+/// The registry evolves block by block: operators are registered, deregistered and
+/// have their stakes updated at the current block set with
+/// [`FakeAvsRegistry::set_current_block`], so that queries at past blocks return the
+/// state at that block.
+#[derive(Debug, Default)]
+pub struct FakeAvsRegistry {
+    state: RwLock<FakeState>,
+}
+
+impl FakeAvsRegistry {
+    /// New empty registry with `quorum_count` quorums, at block 0
+    pub fn new(quorum_count: u8) -> Self {
+        Self {
+            state: RwLock::new(FakeState {
+                quorum_count,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Current block of the registry
+    pub fn current_block(&self) -> u32 {
+        self.state.read().unwrap().current_block
+    }
+
+    /// Set the current block, at which the following updates happen
+    pub fn set_current_block(&self, block: u32) {
+        self.state.write().unwrap().current_block = block;
+    }
+
+    /// Set the number of quorums
+    pub fn set_quorum_count(&self, quorum_count: u8) {
+        self.state.write().unwrap().quorum_count = quorum_count;
+    }
+
+    /// Set the config of `config.quorum_number`. Quorums without a config have an empty
+    /// default one.
+    pub fn set_quorum_config(&self, config: QuorumConfig) {
+        self.state
+            .write()
+            .unwrap()
+            .quorum_configs
+            .insert(config.quorum_number, config);
+    }
+
+    /// Register `operator` in the quorums of `quorum_stakes` at the current block, with
+    /// the given initial stake in each of them.
+    ///
+    /// # Panics
+    ///
+    /// If the operator is already registered or a quorum does not exist.
+    pub fn register_operator(&self, operator: FakeOperator, quorum_stakes: &[(u8, u128)]) {
+        let mut state = self.state.write().unwrap();
+        let block = state.current_block;
+        assert!(
+            state.registration_at(operator.operator_id, block).is_none(),
+            "operator {} is already registered",
+            operator.operator_id
+        );
+        assert!(
+            quorum_stakes.iter().all(|(q, _)| *q < state.quorum_count),
+            "quorum does not exist"
+        );
+        state.registrations.push(Registration {
+            sockets: vec![(block, operator.socket.clone())],
+            operator,
+            quorums: quorum_stakes.iter().map(|(q, _)| *q).collect(),
+            registered_at: block,
+            deregistered_at: None,
+            stakes: quorum_stakes
+                .iter()
+                .map(|(q, stake)| (*q, vec![(block, *stake)]))
+                .collect(),
+        });
+    }
+
+    /// Deregister the operator `operator_id` from all its quorums at the current block
+    ///
+    /// # Panics
+    ///
+    /// If the operator is not registered.
+    pub fn deregister_operator(&self, operator_id: B256) {
+        let mut state = self.state.write().unwrap();
+        let block = state.current_block;
+        state.current_registration_mut(operator_id).deregistered_at = Some(block);
+    }
+
+    /// Update the stake of the operator `operator_id` in `quorum` at the current block
+    ///
+    /// # Panics
+    ///
+    /// If the operator is not registered in `quorum`.
+    pub fn set_operator_stake(&self, operator_id: B256, quorum: u8, stake: u128) {
+        let mut state = self.state.write().unwrap();
+        let block = state.current_block;
+        let registration = state.current_registration_mut(operator_id);
+        assert!(
+            registration.quorums.contains(&quorum),
+            "operator {operator_id} is not registered in quorum {quorum}"
+        );
+        let updates = registration.stakes.entry(quorum).or_default();
+        updates.retain(|(b, _)| *b != block);
+        updates.push((block, stake));
+    }
+
+    /// Update the socket of the operator `operator_id` at the current block
+    ///
+    /// # Panics
+    ///
+    /// If the operator is not registered.
+    pub fn set_operator_socket(&self, operator_id: B256, socket: impl Into<String>) {
+        let mut state = self.state.write().unwrap();
+        let block = state.current_block;
+        let registration = state.current_registration_mut(operator_id);
+        let socket = socket.into();
+        registration.operator.socket = socket.clone();
+        registration.sockets.retain(|(b, _)| *b != block);
+        registration.sockets.push((block, socket));
+    }
+
+    /// Set the indices returned by
+    /// [`AvsRegistryReader::get_check_signatures_indices`] at `reference_block_number`
+    pub fn set_check_signatures_indices(
+        &self,
+        reference_block_number: u32,
+        indices: OperatorStateRetriever::CheckSignaturesIndices,
+    ) {
+        self.state
+            .write()
+            .unwrap()
+            .check_signatures_indices
+            .insert(reference_block_number, indices);
+    }
+}
+
+#[async_trait]
+impl AvsRegistryReader for FakeAvsRegistry {
+    async fn get_quorum_count(&self) -> Result<u8, AvsRegistryError> {
+        Ok(self.state.read().unwrap().quorum_count)
+    }
+
+    async fn get_quorum_config(&self, quorum_number: u8) -> Result<QuorumConfig, AvsRegistryError> {
+        self.state.read().unwrap().quorum_config(quorum_number)
+    }
+
+    async fn get_quorum_configs(&self) -> Result<Vec<QuorumConfig>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        (0..state.quorum_count)
+            .map(|quorum_number| state.quorum_config(quorum_number))
+            .collect()
+    }
+
+    async fn get_operators_stake_in_quorums_at_block(
+        &self,
+        block_number: u32,
+        quorum_numbers: Bytes,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        self.state
+            .read()
+            .unwrap()
+            .operators_at(block_number, &quorum_numbers)
+    }
+
+    async fn get_operators_stake_in_quorums_at_block_operator_id(
+        &self,
+        block_number: u32,
+        operator_id: B256,
+    ) -> Result<(U256, QuorumOperatorSet), AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let quorums = state.operator_quorums_at(operator_id, block_number)?;
+        let bitmap = quorums
+            .iter()
+            .fold(U256::ZERO, |bitmap, q| bitmap | (U256::from(1) << *q));
+        Ok((bitmap, state.operators_at(block_number, &quorums)?))
+    }
+
+    async fn get_operators_stake_in_quorums_at_current_block(
+        &self,
+        quorum_numbers: Bytes,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        state.operators_at(state.current_block, &quorum_numbers)
+    }
+
+    async fn get_operators_stake_in_quorums_of_operator_at_block(
+        &self,
+        operator_id: B256,
+        block_number: u32,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let quorums = state.operator_quorums_at(operator_id, block_number)?;
+        state.operators_at(block_number, &quorums)
+    }
+
+    async fn get_operators_stake_in_quorums_of_operator_at_current_block(
+        &self,
+        operator_id: B256,
+    ) -> Result<QuorumOperatorSet, AvsRegistryError> {
+        let block = self.current_block();
+        self.get_operators_stake_in_quorums_of_operator_at_block(operator_id, block)
+            .await
+    }
+
+    async fn get_operator_stake_in_quorums_of_operator_at_current_block(
+        &self,
+        operator_id: B256,
+    ) -> Result<Vec<OperatorStake>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let block = state.current_block;
+        let registration = state
+            .registration_at(operator_id, block)
+            .ok_or(AvsRegistryError::OperatorNotRegistered)?;
+        Ok(registration
+            .quorums
+            .iter()
+            .map(|q| registration.operator_stake(*q, block))
+            .collect())
+    }
+
+    async fn get_stake_history(
+        &self,
+        operator_id: B256,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        Ok(stake_history(
+            state.stake_changes(operator_id, quorum_number),
+        ))
+    }
+
+    async fn get_total_stake_history(
+        &self,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        Ok(stake_history(state.total_stake_changes(quorum_number)))
+    }
+
+    async fn get_stake_at_block_number_and_index(
+        &self,
+        quorum_number: u8,
+        block_number: u32,
+        operator_id: B256,
+        index: U256,
+    ) -> Result<U256, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let history = stake_history(state.stake_changes(operator_id, quorum_number));
+        let update = usize::try_from(index)
+            .ok()
+            .and_then(|index| history.get(index))
+            .ok_or_else(|| {
+                AvsRegistryError::Reverted(format!(
+                    "FakeAvsRegistry: no stake update at index {index}"
+                ))
+            })?;
+        if !update.is_active_at(block_number) {
+            return Err(AvsRegistryError::Reverted(format!(
+                "FakeAvsRegistry: stake update at index {index} is not active at block {block_number}"
+            )));
+        }
+        Ok(update.stake)
+    }
+
+    async fn get_check_signatures_indices(
+        &self,
+        reference_block_number: u32,
+        _quorum_numbers: Vec<u8>,
+        _non_signer_operator_ids: Vec<FixedBytes<32>>,
+    ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError> {
+        self.state
+            .read()
+            .unwrap()
+            .check_signatures_indices
+            .get(&reference_block_number)
+            .cloned()
+            .ok_or_else(|| {
+                AvsRegistryError::Reverted(format!(
+                    "FakeAvsRegistry: no check signatures indices at block {reference_block_number}"
+                ))
+            })
+    }
+
+    async fn get_operator_id(
+        &self,
+        operator_address: Address,
+    ) -> Result<FixedBytes<32>, AvsRegistryError> {
+        // like the registry coordinator, the id of an unknown operator is zero
+        Ok(self
+            .state
+            .read()
+            .unwrap()
+            .registrations
+            .iter()
+            .find(|r| r.operator.address == operator_address)
+            .map_or(B256::ZERO, |r| r.operator.operator_id))
+    }
+
+    async fn get_operator_from_id(
+        &self,
+        operator_id: [u8; 32],
+    ) -> Result<Address, AvsRegistryError> {
+        Ok(self
+            .state
+            .read()
+            .unwrap()
+            .registrations
+            .iter()
+            .find(|r| r.operator.operator_id == operator_id)
+            .map_or(Address::ZERO, |r| r.operator.address))
+    }
+
+    async fn is_operator_registered(
+        &self,
+        operator_address: Address,
+    ) -> Result<bool, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        Ok(state.registrations.iter().any(|r| {
+            r.operator.address == operator_address && r.is_registered_at(state.current_block)
+        }))
+    }
+
+    async fn query_existing_registered_operator_pub_keys(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<(Vec<Address>, Vec<OperatorPubKeys>), AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let blocks = state.block_range(start_block, stop_block);
+        Ok(state
+            .registrations
+            .iter()
+            .filter(|r| blocks.contains(&u64::from(r.registered_at)))
+            .map(|r| (r.operator.address, r.operator.pub_keys.clone()))
+            .unzip())
+    }
+
+    async fn query_existing_registered_operator_sockets(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<HashMap<FixedBytes<32>, String>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let blocks = state.block_range(start_block, stop_block);
+        // replays the socket updates in block order, the last one of each operator wins
+        let mut updates: Vec<(u32, B256, &String)> = state
+            .registrations
+            .iter()
+            .flat_map(|r| {
+                r.sockets
+                    .iter()
+                    .map(|(block, socket)| (*block, r.operator.operator_id, socket))
+            })
+            .filter(|(block, _, _)| blocks.contains(&u64::from(*block)))
+            .collect();
+        updates.sort_by_key(|(block, _, _)| *block);
+        Ok(updates
+            .into_iter()
+            .map(|(_, operator_id, socket)| (operator_id, socket.clone()))
+            .collect())
+    }
+
+    async fn query_operator_registration_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorRegistrationEvent>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let blocks = state.block_range(start_block, stop_block);
+        let mut events: Vec<OperatorRegistrationEvent> = state
+            .registrations
+            .iter()
+            .flat_map(|r| {
+                let registered = (RegistrationKind::Registered, r.registered_at);
+                let deregistered = r
+                    .deregistered_at
+                    .map(|block| (RegistrationKind::Deregistered, block));
+                std::iter::once(registered)
+                    .chain(deregistered)
+                    .map(|(kind, block)| OperatorRegistrationEvent {
+                        kind,
+                        operator: r.operator.address,
+                        operator_id: r.operator.operator_id,
+                        block_number: block.into(),
+                        transaction_hash: TxHash::ZERO,
+                    })
+            })
+            .filter(|event| blocks.contains(&event.block_number))
+            .collect();
+        events.sort_by_key(|event| event.block_number);
+        Ok(events)
+    }
+
+    async fn query_operator_stake_update_events(
+        &self,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<OperatorStakeUpdateEvent>, AvsRegistryError> {
+        let state = self.state.read().unwrap();
+        let blocks = state.block_range(start_block, stop_block);
+        let mut events: Vec<OperatorStakeUpdateEvent> = state
+            .registrations
+            .iter()
+            .flat_map(|r| {
+                r.quorums.iter().flat_map(|quorum| {
+                    // deregistrations zero the stake of the operator in its quorums
+                    let updates = r.stakes.get(quorum).into_iter().flatten().copied();
+                    updates
+                        .chain(r.deregistered_at.map(|block| (block, 0)))
+                        .map(|(block, stake)| OperatorStakeUpdateEvent {
+                            operator_id: r.operator.operator_id,
+                            quorum: *quorum,
+                            stake: U256::from(stake),
+                            block_number: block.into(),
+                            transaction_hash: TxHash::ZERO,
+                        })
+                })
+            })
+            .filter(|event| blocks.contains(&event.block_number))
+            .collect();
+        events.sort_by_key(|event| event.block_number);
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    fn stakes(operator_set: &QuorumOperatorSet, quorum: u8) -> Vec<(B256, u64)> {
+        operator_set
+            .operators(quorum)
+            .iter()
+            .map(|o| (o.operator_id, o.stake.to()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_operator_state_history() {
+        let registry = FakeAvsRegistry::new(2);
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        let op_b = FakeOperator::new(2, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+
+        registry.set_current_block(10);
+        registry.register_operator(op_a.clone(), &[(0, 100), (1, 50)]);
+        registry.set_current_block(20);
+        registry.register_operator(op_b.clone(), &[(0, 200)]);
+        registry.set_operator_stake(op_a.operator_id, 0, 150);
+        registry.set_current_block(30);
+        registry.deregister_operator(op_a.operator_id);
+
+        let quorums = Bytes::from(vec![0, 1]);
+        let at_15 = registry
+            .get_operators_stake_in_quorums_at_block(15, quorums.clone())
+            .await
+            .unwrap();
+        assert_eq!(stakes(&at_15, 0), [(op_a.operator_id, 100)]);
+        assert_eq!(stakes(&at_15, 1), [(op_a.operator_id, 50)]);
+
+        let at_25 = registry
+            .get_operators_stake_in_quorums_at_block(25, quorums.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            stakes(&at_25, 0),
+            [(op_a.operator_id, 150), (op_b.operator_id, 200)]
+        );
+
+        let current = registry
+            .get_operators_stake_in_quorums_at_current_block(quorums)
+            .await
+            .unwrap();
+        assert_eq!(stakes(&current, 0), [(op_b.operator_id, 200)]);
+        assert!(current.operators(1).is_empty());
+
+        let (bitmap, _) = registry
+            .get_operators_stake_in_quorums_at_block_operator_id(25, op_a.operator_id)
+            .await
+            .unwrap();
+        assert_eq!(bitmap, U256::from(0b11));
+        assert!(!registry.is_operator_registered(op_a.address).await.unwrap());
+        assert!(registry
+            .get_operators_stake_in_quorums_at_block(25, Bytes::from(vec![2]))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_operator_pub_keys_and_sockets() {
+        let registry = FakeAvsRegistry::new(1);
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        let op_b = FakeOperator::new(2, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+        registry.set_current_block(5);
+        registry.register_operator(op_a.clone(), &[(0, 1)]);
+        registry.set_current_block(8);
+        registry.register_operator(op_b.clone(), &[(0, 1)]);
+        registry.set_current_block(12);
+        registry.set_operator_socket(op_b.operator_id, "operator-2:9090");
+
+        let (addresses, _) = registry
+            .query_existing_registered_operator_pub_keys(6, 0)
+            .await
+            .unwrap();
+        assert_eq!(addresses, [op_b.address]);
+
+        let sockets = registry
+            .query_existing_registered_operator_sockets(0, 0)
+            .await
+            .unwrap();
+        assert_eq!(sockets[&op_a.operator_id], "operator-1:8080");
+        assert_eq!(sockets[&op_b.operator_id], "operator-2:9090");
+
+        // the socket in force up to block 10 is the one set at registration
+        let sockets = registry
+            .query_existing_registered_operator_sockets(0, 10)
+            .await
+            .unwrap();
+        assert_eq!(sockets[&op_b.operator_id], "operator-2:8080");
+        let sockets = registry
+            .query_existing_registered_operator_sockets(10, 0)
+            .await
+            .unwrap();
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[&op_b.operator_id], "operator-2:9090");
+
+        assert_eq!(
+            registry.get_operator_id(op_b.address).await.unwrap(),
+            op_b.operator_id
+        );
+        assert_eq!(
+            registry.get_operator_from_id([9; 32]).await.unwrap(),
+            Address::ZERO
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quorum_configs() {
+        let registry = FakeAvsRegistry::new(2);
+        let config = QuorumConfig {
+            quorum_number: 1,
+            minimum_stake: U256::from(1000),
+            ..Default::default()
+        };
+        registry.set_quorum_config(config.clone());
+
+        assert_eq!(registry.get_quorum_config(1).await.unwrap(), config);
+        let configs = registry.get_quorum_configs().await.unwrap();
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].quorum_number, 0);
+        assert!(matches!(
+            registry.get_quorum_config(2).await,
+            Err(AvsRegistryError::QuorumDoesNotExist)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stake_history() {
+        let registry = FakeAvsRegistry::new(1);
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        let op_b = FakeOperator::new(2, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+
+        registry.set_current_block(10);
+        registry.register_operator(op_a.clone(), &[(0, 100)]);
+        registry.set_current_block(20);
+        registry.register_operator(op_b.clone(), &[(0, 200)]);
+        registry.set_operator_stake(op_a.operator_id, 0, 150);
+        registry.set_current_block(30);
+        registry.deregister_operator(op_a.operator_id);
+
+        let update = |block, next, stake: u64| StakeUpdate {
+            update_block_number: block,
+            next_update_block_number: next,
+            stake: U256::from(stake),
+        };
+        assert_eq!(
+            registry
+                .get_stake_history(op_a.operator_id, 0)
+                .await
+                .unwrap(),
+            [
+                update(10, Some(20), 100),
+                update(20, Some(30), 150),
+                update(30, None, 0)
+            ]
+        );
+        assert_eq!(
+            registry.get_total_stake_history(0).await.unwrap(),
+            [
+                update(10, Some(20), 100),
+                update(20, Some(30), 350),
+                update(30, None, 200)
+            ]
+        );
+
+        let stake = registry
+            .get_stake_at_block_number_and_index(0, 25, op_a.operator_id, U256::from(1))
+            .await
+            .unwrap();
+        assert_eq!(stake, U256::from(150));
+        assert!(registry
+            .get_stake_at_block_number_and_index(0, 25, op_a.operator_id, U256::from(0))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_operator_registration_and_stake_update_events() {
+        let registry = FakeAvsRegistry::new(1);
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        registry.set_current_block(10);
+        registry.register_operator(op_a.clone(), &[(0, 100)]);
+        registry.set_current_block(20);
+        registry.set_operator_stake(op_a.operator_id, 0, 150);
+        registry.set_current_block(30);
+        registry.deregister_operator(op_a.operator_id);
+
+        let registrations = registry
+            .query_operator_registration_events(0, 0)
+            .await
+            .unwrap();
+        let kinds: Vec<_> = registrations
+            .iter()
+            .map(|e| (e.kind, e.block_number))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (RegistrationKind::Registered, 10),
+                (RegistrationKind::Deregistered, 30)
+            ]
+        );
+
+        let stake_updates = registry
+            .query_operator_stake_update_events(15, 30)
+            .await
+            .unwrap();
+        let stakes: Vec<_> = stake_updates
+            .iter()
+            .map(|e| (e.block_number, e.stake.to::<u64>()))
+            .collect();
+        assert_eq!(stakes, [(20, 150), (30, 0)]);
+    }
+}
@@ -0,0 +1,27 @@
+//! Mocks of the chain clients, for unit tests that should not need a node.
+//!
+//! Every mock is generated with [`mockall`] from the client trait it implements, e.g.
+//! [`avsregistry::MockAvsRegistryReader`] implements
+//! [`eigen_client_avsregistry::reader::AvsRegistryReader`].
+//!
+//! [`fake_avs_registry::FakeAvsRegistry`] is an in-memory AVS registry whose state is
+//! set from the tests, for services that need consistent registry state rather than
+//! canned responses.
+
+#![doc(
+    html_logo_url = "https://github.com/supernovahs/eigensdk-rs/assets/91280922/bd13caec-3c00-4afc-839a-b83d2890beb5",
+    issue_tracker_base_url = "https://github.com/supernovahs/eigen-rs/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+
+/// Mocks of the AvsRegistry reader, writer and subscriber
+pub mod avsregistry;
+
+/// In-memory AVS registry implementing the AvsRegistry reader
+pub mod fake_avs_registry;
+
+/// Mocks of the EigenLayer core contracts reader and writer
+pub mod elcontracts;
+
+/// Mocks of the transaction sender and EIP-712 signer
+pub mod wallet;
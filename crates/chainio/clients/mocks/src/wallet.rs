@@ -0,0 +1,37 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, Signature};
+use alloy_rpc_types::{TransactionReceipt, TransactionRequest};
+use async_trait::async_trait;
+use eigen_client_eth::wallet::{Eip712Signer, TxId, TxSender, WalletError};
+use mockall::mock;
+
+mock! {
+    /// Mock [`TxSender`]
+    #[derive(Debug)]
+    pub TxSender {}
+
+    #[async_trait]
+    impl TxSender for TxSender {
+        async fn sender_address(&self) -> Result<Address, WalletError>;
+
+        async fn send_transaction(&self, tx: TransactionRequest) -> Result<TxId, WalletError>;
+
+        async fn get_transaction_receipt(
+            &self,
+            tx_id: &TxId,
+        ) -> Result<Option<TransactionReceipt>, WalletError>;
+    }
+}
+
+mock! {
+    /// Mock [`Eip712Signer`]
+    #[derive(Debug)]
+    pub Eip712Signer {}
+
+    #[async_trait]
+    impl Eip712Signer for Eip712Signer {
+        async fn signer_address(&self) -> Result<Address, WalletError>;
+
+        async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, WalletError>;
+    }
+}
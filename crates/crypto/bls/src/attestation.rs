@@ -1,11 +1,8 @@
 use crate::error::BlsError;
-use alloy_primitives::U256;
 use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Projective};
 use ark_ec::{pairing::Pairing, CurveGroup};
-use ark_ff::{BigInteger256, Field, One, PrimeField};
-use eigen_crypto_bn254::utils::{
-    get_g2_generator, mul_by_generator_g1, mul_by_generator_g2, u256_to_bigint256,
-};
+use ark_ff::{BigInteger, BigInteger256, Field, One, PrimeField, Zero};
+use eigen_crypto_bn254::utils::{get_g2_generator, mul_by_generator_g1, mul_by_generator_g2};
 use hex::FromHex;
 use std::fmt::Write;
 use std::ops::{Add, Mul};
@@ -56,21 +53,25 @@ impl Signature {
     }
 }
 
-fn hash_to_g1(digest: &[u8; 32]) -> G1Affine {
-    let one = Fq::one();
+/// Hash of `digest` to G1 as `BN254.hashToG1`: the first point whose x coordinate is
+/// `digest`, read big-endian, plus a counter, with the root `(x^3 + 3)^((p + 1) / 4)`.
+///
+/// Operators sign this point for their signatures to verify on chain.
+pub fn hash_to_g1(digest: &[u8; 32]) -> G1Affine {
     let three = Fq::from(3u64);
-    let mut x = Fq::from_le_bytes_mod_order(digest);
+    let mut exponent = Fq::MODULUS;
+    exponent.add_with_carry(&BigInteger256::from(1u64));
+    exponent.div2();
+    exponent.div2();
 
+    let mut x = Fq::from_be_bytes_mod_order(digest);
     loop {
-        let x_cubed = x.square() * x;
-        let y_squared = x_cubed + three;
-
-        if let Some(y) = y_squared.sqrt() {
-            let point = G1Projective::new(x, y, Fq::one());
-            return point.into_affine();
-        } else {
-            x += &one;
+        let beta = x.square() * x + three;
+        let y = beta.pow(exponent);
+        if y.square() == beta {
+            return G1Affine::new_unchecked(x, y);
         }
+        x += Fq::one();
     }
 }
 
@@ -181,17 +182,11 @@ impl G2Point {
         G2Point { point: added_point }
     }
 
+    /// Point at infinity, the identity of the G2 group
     pub fn new_zero_g2_point() -> Self {
-        G2Point::new(
-            (
-                u256_to_bigint256(U256::from(0)),
-                u256_to_bigint256(U256::from(0)),
-            ),
-            (
-                u256_to_bigint256(U256::from(0)),
-                u256_to_bigint256(U256::from(0)),
-            ),
-        )
+        G2Point {
+            point: G2Projective::zero(),
+        }
     }
 }
 
@@ -213,11 +208,11 @@ impl G1Point {
         G1Point { point: added_point }
     }
 
+    /// Point at infinity, the identity of the G1 group
     pub fn new_zero_g1_point() -> Self {
-        G1Point::new(
-            u256_to_bigint256(U256::from(0)),
-            u256_to_bigint256(U256::from(0)),
-        )
+        G1Point {
+            point: G1Projective::zero(),
+        }
     }
 }
 
@@ -315,4 +310,42 @@ mod tests {
         let keypair_from_new = keypair_result_normal.unwrap();
         assert_eq!(keypair_from_new.priv_key, keypair_from_string.priv_key);
     }
+
+    #[test]
+    fn test_zero_points_are_identity() {
+        let mut rng = thread_rng();
+        let keypair = KeyPair::new(Fr::rand(&mut rng)).unwrap();
+        let pub_key_g1 = keypair.get_pub_key_g1();
+        let pub_key_g2 = keypair.get_pub_key_g2().unwrap();
+
+        let mut zero_g1 = G1Point::new_zero_g1_point();
+        assert!(zero_g1.point.is_zero());
+        let sum_g1 = zero_g1.add(G1Point { point: pub_key_g1 });
+        assert_eq!(sum_g1.point, pub_key_g1);
+
+        let mut zero_g2 = G2Point::new_zero_g2_point();
+        assert!(zero_g2.point.is_zero());
+        let sum_g2 = zero_g2.add(G2Point { point: pub_key_g2 });
+        assert_eq!(sum_g2.point, pub_key_g2);
+
+        assert!(Signature::new_zero_signature().sig().is_zero());
+    }
+
+    #[test]
+    fn test_hash_to_g1_matches_contract() {
+        // x = 1 is on the curve, BN254.hashToG1 returns the generator (1, 2)
+        let mut digest = [0u8; 32];
+        digest[31] = 1;
+        let point = hash_to_g1(&digest);
+        assert_eq!(point.x, Fq::from(1u64));
+        assert_eq!(point.y, Fq::from(2u64));
+
+        // x = 0 is not, 3 has no square root, so x = 1 is the first point
+        assert_eq!(hash_to_g1(&[0u8; 32]), point);
+
+        let mut rng = thread_rng();
+        rng.fill_bytes(&mut digest);
+        let point = hash_to_g1(&digest);
+        assert!(point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve());
+    }
 }
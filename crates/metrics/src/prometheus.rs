@@ -34,7 +34,6 @@ async fn serve_metrics(addr: SocketAddr, handle: PrometheusHandle) -> eyre::Resu
 }
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
     use crate::eigenmetrics::EigenMetrics;
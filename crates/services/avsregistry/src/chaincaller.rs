@@ -1,22 +1,25 @@
 use alloy_primitives::{Bytes, FixedBytes, U256};
 use eigen_chainio_utils::convert_to_bn254_g1_point;
-use eigen_client_avsregistry::reader::AvsRegistryChainReader;
+use eigen_client_avsregistry::reader::AvsRegistryReader;
 use eigen_crypto_bls::attestation::G1Point as BlsG1Point;
 use eigen_crypto_bn254::utils::u256_to_bigint256;
 use eigen_services_operatorsinfo::operatorsinfo_inmemory::OperatorInfoServiceInMemory;
 use eigen_types::operator::{OperatorAvsState, OperatorInfo, OperatorPubKeys, QuorumAvsState};
 use eigen_utils::binding::BLSApkRegistry::G1Point;
 use std::collections::HashMap;
+use std::sync::Arc;
 
+/// Avs registry service reading the operators and quorums state from an
+/// [`AvsRegistryReader`], e.g. the chain reader or an indexer.
 #[derive(Debug)]
 pub struct AvsRegistryServiceChainCaller {
-    avs_registry: AvsRegistryChainReader,
+    avs_registry: Arc<dyn AvsRegistryReader>,
     operators_info_service: OperatorInfoServiceInMemory,
 }
 
 impl AvsRegistryServiceChainCaller {
     pub fn new(
-        avs_registry: AvsRegistryChainReader,
+        avs_registry: Arc<dyn AvsRegistryReader>,
         operators_info_service: OperatorInfoServiceInMemory,
     ) -> Self {
         Self {
@@ -25,7 +28,7 @@ impl AvsRegistryServiceChainCaller {
         }
     }
 
-    pub fn get_avs_registry(&self) -> AvsRegistryChainReader {
+    pub fn get_avs_registry(&self) -> Arc<dyn AvsRegistryReader> {
         self.avs_registry.clone()
     }
 
@@ -38,30 +41,24 @@ impl AvsRegistryServiceChainCaller {
 
         let operators_stakes_in_quorums = self
             .avs_registry
-            .get_operators_stake_in_quorums_at_block(block_num, quorum_nums.clone())
+            .get_operators_stake_in_quorums_at_block(block_num, quorum_nums)
             .await
             .unwrap();
 
-        if operators_stakes_in_quorums.len() != quorum_nums.len() {
-            // throw error
-        }
-
-        for (quorum_id, quorum_num) in quorum_nums.iter().enumerate() {
-            for operator in &operators_stakes_in_quorums[quorum_id] {
-                let info = self.get_operator_info(*operator.operatorId).await;
-                let stake_per_quorum = HashMap::new();
-                let avs_state = operators_avs_state
-                    .entry(FixedBytes(*operator.operatorId))
-                    .or_insert_with(|| OperatorAvsState {
-                        operator_id: *operator.operatorId,
-                        operator_info: OperatorInfo { pub_keys: info },
-                        stake_per_quorum,
-                        block_num: block_num.into(),
-                    });
-                avs_state
-                    .stake_per_quorum
-                    .insert(*quorum_num, U256::from(operator.stake));
-            }
+        for operator in operators_stakes_in_quorums.iter() {
+            let info = self.get_operator_info(*operator.operator_id).await;
+            let stake_per_quorum = HashMap::new();
+            let avs_state = operators_avs_state
+                .entry(operator.operator_id)
+                .or_insert_with(|| OperatorAvsState {
+                    operator_id: *operator.operator_id,
+                    operator_info: OperatorInfo { pub_keys: info },
+                    stake_per_quorum,
+                    block_num: block_num.into(),
+                });
+            avs_state
+                .stake_per_quorum
+                .insert(operator.quorum, operator.stake);
         }
 
         operators_avs_state
@@ -79,20 +76,22 @@ impl AvsRegistryServiceChainCaller {
         let mut quorums_avs_state: HashMap<u8, QuorumAvsState> = HashMap::new();
 
         for quorum_num in quorum_nums.iter() {
-            let mut pub_key_g1 = BlsG1Point::new(
-                u256_to_bigint256(U256::from(0)),
-                u256_to_bigint256(U256::from(0)),
-            );
+            let mut pub_key_g1 = BlsG1Point::new_zero_g1_point();
             let mut total_stake: U256 = U256::from(0);
             for operator in operators_avs_state.values() {
-                if !operator.stake_per_quorum[quorum_num].is_zero() {
+                let stake = operator
+                    .stake_per_quorum
+                    .get(quorum_num)
+                    .copied()
+                    .unwrap_or_default();
+                if !stake.is_zero() {
                     if let Some(pubkeys) = &operator.operator_info.pub_keys {
                         let g1_point = BlsG1Point::new(
                             u256_to_bigint256(pubkeys.g1_pub_key.X),
                             u256_to_bigint256(pubkeys.g1_pub_key.Y),
                         );
-                        pub_key_g1.add(g1_point);
-                        total_stake += operator.stake_per_quorum[quorum_num];
+                        pub_key_g1 = pub_key_g1.add(g1_point);
+                        total_stake += stake;
                     }
                 }
             }
@@ -125,3 +124,79 @@ impl AvsRegistryServiceChainCaller {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use eigen_client_avsregistry::subscriber::AvsRegistryChainSubscriber;
+    use eigen_client_mocks::fake_avs_registry::{FakeAvsRegistry, FakeOperator};
+
+    async fn avs_registry_service(
+        avs_registry: Arc<FakeAvsRegistry>,
+    ) -> AvsRegistryServiceChainCaller {
+        let operators_info_service = OperatorInfoServiceInMemory::new(
+            AvsRegistryChainSubscriber::new(
+                "http://localhost:8545".to_string(),
+                "ws://localhost:8546".to_string(),
+            ),
+            avs_registry.clone(),
+            "ws://localhost:8546".to_string(),
+        )
+        .await;
+        operators_info_service
+            .query_past_registered_operator_events_and_fill_db(0, 0)
+            .await;
+        AvsRegistryServiceChainCaller::new(avs_registry, operators_info_service)
+    }
+
+    #[tokio::test]
+    async fn test_get_quorums_avs_state_at_block() {
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        let op_b = FakeOperator::new(2, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+        let avs_registry = Arc::new(FakeAvsRegistry::new(2));
+        avs_registry.set_current_block(10);
+        avs_registry.register_operator(op_a.clone(), &[(0, 100), (1, 10)]);
+        avs_registry.register_operator(op_b.clone(), &[(0, 200), (1, 20)]);
+        avs_registry.set_current_block(20);
+        avs_registry.set_operator_stake(op_b.operator_id, 0, 300);
+
+        let service = avs_registry_service(avs_registry).await;
+
+        let operators = service
+            .get_operators_avs_state_at_block(10, Bytes::from(vec![0, 1]))
+            .await;
+        let op_a_state = &operators[&op_a.operator_id];
+        assert_eq!(op_a_state.stake_per_quorum[&0], U256::from(100));
+        assert_eq!(op_a_state.stake_per_quorum[&1], U256::from(10));
+        assert_eq!(
+            op_a_state
+                .operator_info
+                .pub_keys
+                .as_ref()
+                .unwrap()
+                .g1_pub_key
+                .X,
+            U256::from(1)
+        );
+
+        let quorums_at_10 = service
+            .get_quorums_avs_state_at_block(Bytes::from(vec![0, 1]), 10)
+            .await;
+        assert_eq!(quorums_at_10[&0].total_stake, U256::from(300));
+        assert_eq!(quorums_at_10[&1].total_stake, U256::from(30));
+        // both operators have the generator as pubkey
+        let mut generator = BlsG1Point::new(
+            u256_to_bigint256(U256::from(1)),
+            u256_to_bigint256(U256::from(2)),
+        );
+        let doubled = convert_to_bn254_g1_point(generator.add(generator.clone()).point);
+        assert_eq!(quorums_at_10[&0].agg_pub_key_g1.X, doubled.X);
+        assert_eq!(quorums_at_10[&0].agg_pub_key_g1.Y, doubled.Y);
+
+        let quorums_at_20 = service
+            .get_quorums_avs_state_at_block(Bytes::from(vec![0]), 20)
+            .await;
+        assert_eq!(quorums_at_20[&0].total_stake, U256::from(400));
+    }
+}
@@ -4,3 +4,4 @@
 )]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 pub mod chaincaller;
+pub mod snapshot;
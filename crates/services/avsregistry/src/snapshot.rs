@@ -0,0 +1,344 @@
+//! Snapshot of the AVS operator set at a block, for audits and payouts.
+use alloy_primitives::{Address, Bytes, B256, U256};
+use eigen_client_avsregistry::{error::AvsRegistryError, reader::AvsRegistryReader};
+use eigen_types::operator::OperatorPubKeys;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+
+/// G1 pubkey of an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G1PubKey {
+    pub x: U256,
+    pub y: U256,
+}
+
+/// G2 pubkey of an operator, coordinates in the order of the BLSApkRegistry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G2PubKey {
+    pub x: [U256; 2],
+    pub y: [U256; 2],
+}
+
+/// Operator of a [`OperatorSetSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorSnapshot {
+    pub operator: Address,
+    pub operator_id: B256,
+    /// Last socket of the operator, `None` if it never emitted one
+    pub socket: Option<String>,
+    /// Pubkeys of the operator, `None` if their registration was not found
+    pub g1_pub_key: Option<G1PubKey>,
+    pub g2_pub_key: Option<G2PubKey>,
+    /// Stake of the operator in each of the snapshot quorums it is registered in
+    pub stakes: BTreeMap<u8, U256>,
+}
+
+/// Operators of the AVS with their ids, sockets, pubkeys and stakes at a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorSetSnapshot {
+    pub block_number: u32,
+    pub quorum_numbers: Vec<u8>,
+    /// Operators ordered by operator id
+    pub operators: Vec<OperatorSnapshot>,
+}
+
+/// Change of the stake of an operator in a quorum between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakeChange {
+    pub operator_id: B256,
+    pub quorum: u8,
+    pub before: U256,
+    pub after: U256,
+}
+
+/// Differences from a snapshot to a later one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OperatorSetDiff {
+    /// Operators only in the later snapshot
+    pub added: Vec<OperatorSnapshot>,
+    /// Operators only in the earlier snapshot
+    pub removed: Vec<OperatorSnapshot>,
+    /// Stake changes of the operators in both snapshots, a missing stake counting as zero
+    pub stake_changes: Vec<StakeChange>,
+    /// Socket changes of the operators in both snapshots as `(operator_id, before, after)`
+    pub socket_changes: Vec<(B256, Option<String>, Option<String>)>,
+}
+
+impl OperatorSetDiff {
+    /// Whether the snapshots have the same operators, stakes and sockets
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.stake_changes.is_empty()
+            && self.socket_changes.is_empty()
+    }
+}
+
+impl OperatorSetSnapshot {
+    /// Snapshot of the operators of `quorum_numbers` at `block_number`.
+    ///
+    /// Pubkeys and sockets are read from the events emitted since `start_block`, which
+    /// should be at most the block the AVS registry contracts were deployed at.
+    pub async fn at_block(
+        avs_registry: &dyn AvsRegistryReader,
+        quorum_numbers: &[u8],
+        block_number: u32,
+        start_block: u64,
+    ) -> Result<Self, AvsRegistryError> {
+        let operator_set = avs_registry
+            .get_operators_stake_in_quorums_at_block(
+                block_number,
+                Bytes::copy_from_slice(quorum_numbers),
+            )
+            .await?;
+        let (addresses, pub_keys) = avs_registry
+            .query_existing_registered_operator_pub_keys(start_block, block_number.into())
+            .await?;
+        let pub_keys: HashMap<Address, OperatorPubKeys> =
+            addresses.into_iter().zip(pub_keys).collect();
+        let mut sockets = avs_registry
+            .query_existing_registered_operator_sockets(start_block, block_number.into())
+            .await?;
+
+        let mut operators: BTreeMap<B256, OperatorSnapshot> = BTreeMap::new();
+        for stake in operator_set.iter() {
+            let operator = operators.entry(stake.operator_id).or_insert_with(|| {
+                let keys = pub_keys.get(&stake.operator);
+                OperatorSnapshot {
+                    operator: stake.operator,
+                    operator_id: stake.operator_id,
+                    socket: sockets.remove(&stake.operator_id),
+                    g1_pub_key: keys.map(|keys| G1PubKey {
+                        x: keys.g1_pub_key.X,
+                        y: keys.g1_pub_key.Y,
+                    }),
+                    g2_pub_key: keys.map(|keys| G2PubKey {
+                        x: keys.g2_pub_key.X,
+                        y: keys.g2_pub_key.Y,
+                    }),
+                    stakes: BTreeMap::new(),
+                }
+            });
+            operator.stakes.insert(stake.quorum, stake.stake);
+        }
+
+        Ok(Self {
+            block_number,
+            quorum_numbers: quorum_numbers.to_vec(),
+            operators: operators.into_values().collect(),
+        })
+    }
+
+    /// Pretty printed JSON of the snapshot
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Snapshot of its JSON
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Write the snapshot as CSV, one row per operator with a `stake_quorum_<n>` column
+    /// per quorum. Missing values are empty.
+    pub fn write_csv<W: io::Write>(&self, writer: W) -> csv::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        let mut header = [
+            "operator",
+            "operator_id",
+            "socket",
+            "g1_x",
+            "g1_y",
+            "g2_x0",
+            "g2_x1",
+            "g2_y0",
+            "g2_y1",
+        ]
+        .map(String::from)
+        .to_vec();
+        header.extend(
+            self.quorum_numbers
+                .iter()
+                .map(|quorum| format!("stake_quorum_{quorum}")),
+        );
+        csv.write_record(&header)?;
+
+        for operator in &self.operators {
+            let mut record = vec![
+                operator.operator.to_string(),
+                operator.operator_id.to_string(),
+                operator.socket.clone().unwrap_or_default(),
+            ];
+            let g1 = operator.g1_pub_key.map(|key| [key.x, key.y]);
+            let g2 = operator
+                .g2_pub_key
+                .map(|key| [key.x[0], key.x[1], key.y[0], key.y[1]]);
+            record.extend(optional_columns(g1));
+            record.extend(optional_columns(g2));
+            record.extend(self.quorum_numbers.iter().map(|quorum| {
+                operator
+                    .stakes
+                    .get(quorum)
+                    .map(U256::to_string)
+                    .unwrap_or_default()
+            }));
+            csv.write_record(&record)?;
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    /// CSV of the snapshot, see [`OperatorSetSnapshot::write_csv`]
+    pub fn to_csv(&self) -> csv::Result<String> {
+        let mut csv = Vec::new();
+        self.write_csv(&mut csv)?;
+        Ok(String::from_utf8(csv).expect("CSV of strings is UTF-8"))
+    }
+
+    /// Differences from this snapshot to `later`
+    pub fn diff(&self, later: &Self) -> OperatorSetDiff {
+        let before: HashMap<B256, &OperatorSnapshot> = self
+            .operators
+            .iter()
+            .map(|operator| (operator.operator_id, operator))
+            .collect();
+        let after: HashMap<B256, &OperatorSnapshot> = later
+            .operators
+            .iter()
+            .map(|operator| (operator.operator_id, operator))
+            .collect();
+
+        let mut diff = OperatorSetDiff {
+            removed: self
+                .operators
+                .iter()
+                .filter(|operator| !after.contains_key(&operator.operator_id))
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+        for operator in &later.operators {
+            let Some(previous) = before.get(&operator.operator_id) else {
+                diff.added.push(operator.clone());
+                continue;
+            };
+            let mut quorums: Vec<u8> = previous
+                .stakes
+                .keys()
+                .chain(operator.stakes.keys())
+                .copied()
+                .collect();
+            quorums.sort_unstable();
+            quorums.dedup();
+            for quorum in quorums {
+                let stake_before = previous.stakes.get(&quorum).copied().unwrap_or_default();
+                let stake_after = operator.stakes.get(&quorum).copied().unwrap_or_default();
+                if stake_before != stake_after {
+                    diff.stake_changes.push(StakeChange {
+                        operator_id: operator.operator_id,
+                        quorum,
+                        before: stake_before,
+                        after: stake_after,
+                    });
+                }
+            }
+            if previous.socket != operator.socket {
+                diff.socket_changes.push((
+                    operator.operator_id,
+                    previous.socket.clone(),
+                    operator.socket.clone(),
+                ));
+            }
+        }
+        diff
+    }
+}
+
+/// CSV columns of `values`, empty if `None`
+fn optional_columns<const N: usize>(values: Option<[U256; N]>) -> Vec<String> {
+    match values {
+        Some(values) => values.iter().map(U256::to_string).collect(),
+        None => vec![String::new(); N],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use eigen_client_mocks::fake_avs_registry::{FakeAvsRegistry, FakeOperator};
+    use eigen_utils::binding::BLSApkRegistry::G2Point;
+
+    #[tokio::test]
+    async fn test_snapshot_export_and_diff() {
+        let registry = FakeAvsRegistry::new(2);
+        let op_a = FakeOperator::new(1, address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2"));
+        let mut op_b = FakeOperator::new(2, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+        op_b.pub_keys.g2_pub_key = G2Point {
+            X: [U256::from(3), U256::from(4)],
+            Y: [U256::from(5), U256::from(6)],
+        };
+        registry.set_current_block(10);
+        registry.register_operator(op_a.clone(), &[(0, 100), (1, 50)]);
+        registry.set_current_block(20);
+        registry.register_operator(op_b.clone(), &[(0, 200)]);
+        registry.set_operator_stake(op_a.operator_id, 0, 150);
+        registry.set_operator_socket(op_a.operator_id, "operator-1:9090");
+
+        let at_10 = OperatorSetSnapshot::at_block(&registry, &[0, 1], 10, 0)
+            .await
+            .unwrap();
+        let at_20 = OperatorSetSnapshot::at_block(&registry, &[0, 1], 20, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(at_20.operators.len(), 2);
+        assert_eq!(
+            at_20.operators[0].socket.as_deref(),
+            Some("operator-1:9090")
+        );
+        assert_eq!(
+            at_20.operators[0].g1_pub_key,
+            Some(G1PubKey {
+                x: U256::from(1),
+                y: U256::from(2)
+            })
+        );
+        assert_eq!(
+            OperatorSetSnapshot::from_json(&at_20.to_json().unwrap()).unwrap(),
+            at_20
+        );
+
+        let csv = at_20.to_csv().unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "operator,operator_id,socket,g1_x,g1_y,g2_x0,g2_x1,g2_y0,g2_y1,stake_quorum_0,stake_quorum_1"
+        );
+        assert!(lines[2].ends_with(",1,2,3,4,5,6,200,"));
+
+        let diff = at_10.diff(&at_20);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].operator_id, op_b.operator_id);
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.stake_changes,
+            [StakeChange {
+                operator_id: op_a.operator_id,
+                quorum: 0,
+                before: U256::from(100),
+                after: U256::from(150),
+            }]
+        );
+        assert_eq!(
+            diff.socket_changes,
+            [(
+                op_a.operator_id,
+                Some("operator-1:8080".to_string()),
+                Some("operator-1:9090".to_string())
+            )]
+        );
+        assert!(at_20.diff(&at_20).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::signature_checker::{g1_coordinates, g2_coordinates};
 use eigen_crypto_bls::attestation::{G1Point, G2Point, Signature};
 use eigen_services_avsregistry::chaincaller::AvsRegistryServiceChainCaller;
 use eigen_types::{
@@ -6,7 +7,9 @@ use eigen_types::{
 };
 
 use alloy_primitives::{FixedBytes, U256};
+use ark_ec::CurveGroup;
 use eigen_crypto_bn254::utils::u256_to_bigint256;
+use eigen_utils::binding::IBLSSignatureChecker::{self, NonSignerStakesAndSignature};
 use std::collections::HashMap;
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use tokio::time::{self, Duration};
@@ -14,7 +17,6 @@ use tokio::time::{self, Duration};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::sync::Arc;
 
-#[allow(unused)]
 #[derive(Debug, Clone)]
 pub struct BlsAggregationServiceResponse {
     task_index: TaskIndex,
@@ -29,6 +31,44 @@ pub struct BlsAggregationServiceResponse {
     non_signer_stake_indices: Vec<Vec<u32>>,
 }
 
+impl BlsAggregationServiceResponse {
+    /// Index of the aggregated task
+    pub fn task_index(&self) -> TaskIndex {
+        self.task_index
+    }
+
+    /// Digest of the task response signed by the aggregated signature
+    pub fn task_response_digest(&self) -> TaskResponseDigest {
+        self.task_response_digest
+    }
+
+    /// Non-signer stakes and aggregated signature argument of
+    /// `BLSSignatureChecker.checkSignatures`, to submit the response or dry run it with
+    /// `AvsRegistryChainReader::check_signatures`
+    pub fn non_signer_stakes_and_signature(&self) -> NonSignerStakesAndSignature {
+        NonSignerStakesAndSignature {
+            nonSignerQuorumBitmapIndices: self.non_signer_quorum_bitmap_indices.clone(),
+            nonSignerPubkeys: self.non_signers_pub_keys_g1.iter().map(g1_point).collect(),
+            quorumApks: self.quorum_apks_g1.iter().map(g1_point).collect(),
+            apkG2: g2_point(&self.signers_apk_g2),
+            sigma: g1_point(&self.signers_agg_sig_g1.get_g1_point()),
+            quorumApkIndices: self.quorum_apk_indices.clone(),
+            totalStakeIndices: self.total_stake_indices.clone(),
+            nonSignerStakeIndices: self.non_signer_stake_indices.clone(),
+        }
+    }
+}
+
+fn g1_point(point: &G1Point) -> IBLSSignatureChecker::G1Point {
+    let (x, y) = g1_coordinates(point.point.into_affine());
+    IBLSSignatureChecker::G1Point { X: x, Y: y }
+}
+
+fn g2_point(point: &G2Point) -> IBLSSignatureChecker::G2Point {
+    let (x, y) = g2_coordinates(point.point.into_affine());
+    IBLSSignatureChecker::G2Point { X: x, Y: y }
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregatedOperators {
     signers_apk_g2: G2Point,
@@ -266,7 +306,7 @@ impl BlsAggregatorService {
                                 quorum_apks_g1: quorum_apks_g1.clone(),
                                 signers_apk_g2: aggregate_response.signers_apk_g2,
                                 signers_agg_sig_g1: aggregate_response.signers_agg_sig_g1,
-                                non_signer_quorum_bitmap_indices: indices.clone().quorumApkIndices,
+                                non_signer_quorum_bitmap_indices: indices.nonSignerQuorumBitmapIndices,
                                 quorum_apk_indices: indices.quorumApkIndices,
                                 total_stake_indices: indices.totalStakeIndices,
                                 non_signer_stake_indices: indices.nonSignerStakeIndices
@@ -344,3 +384,59 @@ impl BlsAggregatorService {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use eigen_crypto_bn254::utils::{mul_by_generator_g1, mul_by_generator_g2};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_g1_point() {
+        let generator = G1Point {
+            point: mul_by_generator_g1(Fr::from(1)).unwrap(),
+        };
+        let point = g1_point(&generator);
+        assert_eq!(point.X, U256::from(1));
+        assert_eq!(point.Y, U256::from(2));
+
+        let zero = g1_point(&G1Point::new_zero_g1_point());
+        assert_eq!(zero.X, U256::ZERO);
+        assert_eq!(zero.Y, U256::ZERO);
+    }
+
+    #[test]
+    fn test_g2_point() {
+        let generator = G2Point {
+            point: mul_by_generator_g2(Fr::from(1)).unwrap(),
+        };
+        let point = g2_point(&generator);
+        assert_eq!(
+            point.X,
+            [
+                U256::from_str(
+                    "11559732032986387107991004021392285783925812861821192530917403151452391805634"
+                )
+                .unwrap(),
+                U256::from_str(
+                    "10857046999023057135944570762232829481370756359578518086990519993285655852781"
+                )
+                .unwrap(),
+            ]
+        );
+        assert_eq!(
+            point.Y,
+            [
+                U256::from_str(
+                    "4082367875863433681332203403145435568316851327593401208105741076214120093531"
+                )
+                .unwrap(),
+                U256::from_str(
+                    "8495653923123431417604973247489272438418190587263600148770280649306958101930"
+                )
+                .unwrap(),
+            ]
+        );
+    }
+}
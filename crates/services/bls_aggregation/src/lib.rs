@@ -5,3 +5,4 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 pub mod bls_agg;
+pub mod signature_checker;
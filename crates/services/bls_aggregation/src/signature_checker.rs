@@ -0,0 +1,391 @@
+//! Local reimplementation of `BLSSignatureChecker.checkSignatures`.
+//!
+//! Checks an attestation against the registry state at its reference block, given by
+//! the caller (e.g. from an indexer), instead of reading it from the contracts.
+//!
+//! The checks that need the chain are left to the caller: that the reference block is
+//! before the current block and, when the contract forbids stale stakes, that every
+//! quorum was updated within the withdrawal delay before the reference block.
+use alloy_primitives::{keccak256, B256, U256};
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use eigen_crypto_bls::attestation::hash_to_g1;
+use eigen_crypto_bn254::utils::u256_to_bigint256;
+use eigen_types::{
+    avs::QuorumStakeTotals,
+    operator::{operator_id_from_g1_pub_key, OperatorId, QuorumOperatorSet},
+};
+use eigen_utils::binding::{
+    BLSApkRegistry,
+    IBLSSignatureChecker::{self, NonSignerStakesAndSignature},
+};
+use std::collections::HashMap;
+use std::ops::{Mul, Neg};
+use thiserror::Error;
+
+/// Registry state at the reference block of an attestation
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceBlockState {
+    /// Aggregate pubkey of each quorum
+    pub quorum_apks: HashMap<u8, IBLSSignatureChecker::G1Point>,
+    /// Operators of each quorum with their stake
+    pub operator_set: QuorumOperatorSet,
+}
+
+/// Reason an attestation fails the signature check, matching the reverts of
+/// `BLSSignatureChecker.checkSignatures`
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SignatureCheckError {
+    /// No quorum numbers to check
+    #[error("empty quorum input")]
+    EmptyQuorums,
+
+    /// Quorum numbers are not in strictly ascending order
+    #[error("quorum numbers are not ordered")]
+    QuorumsNotOrdered,
+
+    /// Per-quorum inputs do not have one entry per quorum number
+    #[error("input quorum length mismatch")]
+    QuorumLengthMismatch,
+
+    /// Non-signer pubkeys and quorum bitmap indices differ in length
+    #[error("input nonsigner length mismatch")]
+    NonSignerLengthMismatch,
+
+    /// Non-signer pubkeys are not sorted by ascending operator id
+    #[error("nonSignerPubkeys not sorted")]
+    NonSignersNotSorted,
+
+    /// Reference block state has no apk for the quorum
+    #[error("no apk for quorum {0} at the reference block")]
+    MissingQuorumApk(u8),
+
+    /// Quorum apk differs from the apk of the quorum at the reference block
+    #[error("quorum apk of quorum {0} does not match the apk at the reference block")]
+    QuorumApkMismatch(u8),
+
+    /// Point is not on the curve or not in the prime order subgroup
+    #[error("invalid curve point")]
+    InvalidPoint,
+
+    /// Aggregated signature does not verify against the signers apk
+    #[error("signature is invalid")]
+    InvalidSignature,
+}
+
+/// Signed and total stake of each of `quorum_numbers` for the attestation of
+/// `msg_hash` by `params`, with the checks of `BLSSignatureChecker.checkSignatures`
+/// except the reference block and stale stakes ones, see the [module docs](self).
+///
+/// The signers apk is the sum of the quorum apks minus each non-signer pubkey once per
+/// quorum it is in, and it is checked against `params.apkG2` together with the
+/// signature using the challenge of the contract. The indices of `params` only locate
+/// the state in the contract histories and are not used.
+pub fn check_signatures(
+    msg_hash: B256,
+    quorum_numbers: &[u8],
+    reference_block_number: u32,
+    params: &NonSignerStakesAndSignature,
+    state: &ReferenceBlockState,
+) -> Result<QuorumStakeTotals, SignatureCheckError> {
+    if quorum_numbers.is_empty() {
+        return Err(SignatureCheckError::EmptyQuorums);
+    }
+    if quorum_numbers.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(SignatureCheckError::QuorumsNotOrdered);
+    }
+    let quorum_count = quorum_numbers.len();
+    if params.quorumApks.len() != quorum_count
+        || params.quorumApkIndices.len() != quorum_count
+        || params.totalStakeIndices.len() != quorum_count
+        || params.nonSignerStakeIndices.len() != quorum_count
+    {
+        return Err(SignatureCheckError::QuorumLengthMismatch);
+    }
+    if params.nonSignerPubkeys.len() != params.nonSignerQuorumBitmapIndices.len() {
+        return Err(SignatureCheckError::NonSignerLengthMismatch);
+    }
+
+    let non_signer_ids: Vec<OperatorId> = params
+        .nonSignerPubkeys
+        .iter()
+        .map(|pubkey| {
+            operator_id_from_g1_pub_key(BLSApkRegistry::G1Point {
+                X: pubkey.X,
+                Y: pubkey.Y,
+            })
+            .into()
+        })
+        .collect();
+    if non_signer_ids.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(SignatureCheckError::NonSignersNotSorted);
+    }
+
+    let mut apk = G1Projective::zero();
+    for (pubkey, operator_id) in params.nonSignerPubkeys.iter().zip(&non_signer_ids) {
+        let signing_quorums = quorum_numbers
+            .iter()
+            .filter(|quorum| state.operator_set.stake(**quorum, *operator_id).is_some())
+            .count();
+        apk -= g1_affine(pubkey)?.mul(Fr::from(signing_quorums as u64));
+    }
+
+    let mut signed_stake_for_quorum = Vec::with_capacity(quorum_count);
+    let mut total_stake_for_quorum = Vec::with_capacity(quorum_count);
+    for (quorum_number, quorum_apk) in quorum_numbers.iter().copied().zip(&params.quorumApks) {
+        let expected_apk = state
+            .quorum_apks
+            .get(&quorum_number)
+            .ok_or(SignatureCheckError::MissingQuorumApk(quorum_number))?;
+        if expected_apk.X != quorum_apk.X || expected_apk.Y != quorum_apk.Y {
+            return Err(SignatureCheckError::QuorumApkMismatch(quorum_number));
+        }
+        apk += g1_affine(quorum_apk)?;
+
+        let total_stake = state.operator_set.total_stake(quorum_number);
+        let non_signer_stake = non_signer_ids
+            .iter()
+            .filter_map(|operator_id| state.operator_set.stake(quorum_number, *operator_id))
+            .fold(U256::ZERO, |total, stake| total + stake);
+        signed_stake_for_quorum.push(total_stake.saturating_sub(non_signer_stake));
+        total_stake_for_quorum.push(total_stake);
+    }
+
+    let apk = apk.into_affine();
+    let apk_g2 = g2_affine(&params.apkG2)?;
+    let sigma = g1_affine(&params.sigma)?;
+    if !verify_signature_and_apk(msg_hash, apk, apk_g2, sigma) {
+        return Err(SignatureCheckError::InvalidSignature);
+    }
+
+    let mut signatory_record = reference_block_number.to_be_bytes().to_vec();
+    for operator_id in &non_signer_ids {
+        signatory_record.extend_from_slice(operator_id.as_slice());
+    }
+
+    Ok(QuorumStakeTotals {
+        quorum_numbers: quorum_numbers.to_vec(),
+        signed_stake_for_quorum,
+        total_stake_for_quorum,
+        signatory_record_hash: keccak256(signatory_record),
+    })
+}
+
+/// `BLSSignatureChecker.trySignatureAndApkVerification`: whether `sigma` signs
+/// `msg_hash` for `apk_g2` and `apk_g2` has the same secret key as `apk`, checked in a
+/// single pairing with the challenge gamma.
+fn verify_signature_and_apk(
+    msg_hash: B256,
+    apk: G1Affine,
+    apk_g2: G2Affine,
+    sigma: G1Affine,
+) -> bool {
+    let (apk_x, apk_y) = g1_coordinates(apk);
+    let (apk_g2_x, apk_g2_y) = g2_coordinates(apk_g2);
+    let (sigma_x, sigma_y) = g1_coordinates(sigma);
+    let mut challenge = msg_hash.to_vec();
+    for coordinate in [
+        apk_x,
+        apk_y,
+        apk_g2_x[0],
+        apk_g2_x[1],
+        apk_g2_y[0],
+        apk_g2_y[1],
+        sigma_x,
+        sigma_y,
+    ] {
+        challenge.extend_from_slice(&coordinate.to_be_bytes::<32>());
+    }
+    let gamma = Fr::from_be_bytes_mod_order(keccak256(challenge).as_slice());
+
+    // the arkworks generators are the ones of the contract
+    let lhs = (sigma + apk.mul(gamma)).into_affine();
+    let rhs = (hash_to_g1(&msg_hash.0) + G1Affine::generator().mul(gamma)).into_affine();
+    Bn254::multi_pairing([lhs, rhs], [G2Affine::generator().neg(), apk_g2]).is_zero()
+}
+
+/// Contract coordinates of `point`, (0, 0) for the point at infinity
+pub(crate) fn g1_coordinates(point: G1Affine) -> (U256, U256) {
+    match point.xy() {
+        Some((x, y)) => (fq_to_u256(*x), fq_to_u256(*y)),
+        None => (U256::ZERO, U256::ZERO),
+    }
+}
+
+/// Contract coordinates of `point`, with the imaginary part of each coordinate first
+pub(crate) fn g2_coordinates(point: G2Affine) -> ([U256; 2], [U256; 2]) {
+    match point.xy() {
+        Some((x, y)) => (
+            [fq_to_u256(x.c1), fq_to_u256(x.c0)],
+            [fq_to_u256(y.c1), fq_to_u256(y.c0)],
+        ),
+        None => ([U256::ZERO; 2], [U256::ZERO; 2]),
+    }
+}
+
+fn fq_to_u256(element: Fq) -> U256 {
+    U256::from_limbs(element.into_bigint().0)
+}
+
+fn u256_to_fq(value: U256) -> Result<Fq, SignatureCheckError> {
+    Fq::from_bigint(u256_to_bigint256(value)).ok_or(SignatureCheckError::InvalidPoint)
+}
+
+fn g1_affine(point: &IBLSSignatureChecker::G1Point) -> Result<G1Affine, SignatureCheckError> {
+    if point.X.is_zero() && point.Y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    let point = G1Affine::new_unchecked(u256_to_fq(point.X)?, u256_to_fq(point.Y)?);
+    if !point.is_on_curve() {
+        return Err(SignatureCheckError::InvalidPoint);
+    }
+    Ok(point)
+}
+
+fn g2_affine(point: &IBLSSignatureChecker::G2Point) -> Result<G2Affine, SignatureCheckError> {
+    if point.X.iter().chain(&point.Y).all(U256::is_zero) {
+        return Ok(G2Affine::zero());
+    }
+    let point = G2Affine::new_unchecked(
+        Fq2::new(u256_to_fq(point.X[1])?, u256_to_fq(point.X[0])?),
+        Fq2::new(u256_to_fq(point.Y[1])?, u256_to_fq(point.Y[0])?),
+    );
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SignatureCheckError::InvalidPoint);
+    }
+    Ok(point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use eigen_crypto_bn254::utils::{mul_by_generator_g1, mul_by_generator_g2};
+    use eigen_types::operator::OperatorStake;
+
+    struct TestOperator {
+        secret_key: Fr,
+        pubkey: IBLSSignatureChecker::G1Point,
+        operator_id: OperatorId,
+    }
+
+    fn operator(secret_key: u64) -> TestOperator {
+        let secret_key = Fr::from(secret_key);
+        let (x, y) = g1_coordinates(mul_by_generator_g1(secret_key).unwrap().into_affine());
+        let operator_id = operator_id_from_g1_pub_key(BLSApkRegistry::G1Point { X: x, Y: y });
+        TestOperator {
+            secret_key,
+            pubkey: IBLSSignatureChecker::G1Point { X: x, Y: y },
+            operator_id: operator_id.into(),
+        }
+    }
+
+    fn stake(operator: &TestOperator, quorum: u8, stake: u64) -> OperatorStake {
+        OperatorStake {
+            operator: Address::ZERO,
+            operator_id: operator.operator_id,
+            quorum,
+            stake: U256::from(stake),
+        }
+    }
+
+    fn apk(operators: &[&TestOperator]) -> IBLSSignatureChecker::G1Point {
+        let secret_key = operators.iter().map(|o| o.secret_key).sum();
+        let (x, y) = g1_coordinates(mul_by_generator_g1(secret_key).unwrap().into_affine());
+        IBLSSignatureChecker::G1Point { X: x, Y: y }
+    }
+
+    /// Quorum 0 has the three operators and quorum 1 the first and the last, the second
+    /// operator does not sign `msg_hash`
+    fn attestation(msg_hash: B256) -> (NonSignerStakesAndSignature, ReferenceBlockState) {
+        let operators = [operator(11), operator(22), operator(33)];
+        let [first, second, third] = &operators;
+
+        let mut state = ReferenceBlockState::default();
+        state.quorum_apks.insert(0, apk(&[first, second, third]));
+        state.quorum_apks.insert(1, apk(&[first, third]));
+        state.operator_set.insert(
+            0,
+            vec![
+                stake(first, 0, 100),
+                stake(second, 0, 200),
+                stake(third, 0, 300),
+            ],
+        );
+        state
+            .operator_set
+            .insert(1, vec![stake(first, 1, 10), stake(third, 1, 30)]);
+
+        // the signers apk counts each signer once per quorum it is in
+        let signers_key = (first.secret_key + third.secret_key) * Fr::from(2u64);
+        let (apk_g2_x, apk_g2_y) =
+            g2_coordinates(mul_by_generator_g2(signers_key).unwrap().into_affine());
+        let (sigma_x, sigma_y) =
+            g1_coordinates(hash_to_g1(&msg_hash.0).mul(signers_key).into_affine());
+
+        let params = NonSignerStakesAndSignature {
+            nonSignerQuorumBitmapIndices: vec![0],
+            nonSignerPubkeys: vec![second.pubkey.clone()],
+            quorumApks: vec![state.quorum_apks[&0].clone(), state.quorum_apks[&1].clone()],
+            apkG2: IBLSSignatureChecker::G2Point {
+                X: apk_g2_x,
+                Y: apk_g2_y,
+            },
+            sigma: IBLSSignatureChecker::G1Point {
+                X: sigma_x,
+                Y: sigma_y,
+            },
+            quorumApkIndices: vec![0, 0],
+            totalStakeIndices: vec![0, 0],
+            nonSignerStakeIndices: vec![vec![0], vec![]],
+        };
+        (params, state)
+    }
+
+    #[test]
+    fn test_check_signatures() {
+        let msg_hash = B256::repeat_byte(7);
+        let (params, state) = attestation(msg_hash);
+
+        let totals = check_signatures(msg_hash, &[0, 1], 42, &params, &state).unwrap();
+        assert_eq!(
+            totals.signed_stake_for_quorum,
+            vec![U256::from(400), U256::from(40)]
+        );
+        assert_eq!(
+            totals.total_stake_for_quorum,
+            vec![U256::from(600), U256::from(40)]
+        );
+
+        let mut signatory_record = 42u32.to_be_bytes().to_vec();
+        signatory_record.extend_from_slice(operator(22).operator_id.as_slice());
+        assert_eq!(totals.signatory_record_hash, keccak256(signatory_record));
+    }
+
+    #[test]
+    fn test_check_signatures_rejects_invalid_attestations() {
+        let msg_hash = B256::repeat_byte(7);
+        let (params, state) = attestation(msg_hash);
+
+        assert_eq!(
+            check_signatures(B256::repeat_byte(8), &[0, 1], 42, &params, &state),
+            Err(SignatureCheckError::InvalidSignature)
+        );
+        assert_eq!(
+            check_signatures(msg_hash, &[1, 0], 42, &params, &state),
+            Err(SignatureCheckError::QuorumsNotOrdered)
+        );
+        assert_eq!(
+            check_signatures(msg_hash, &[0], 42, &params, &state),
+            Err(SignatureCheckError::QuorumLengthMismatch)
+        );
+
+        let mut wrong_apk = params.clone();
+        wrong_apk.quorumApks.swap(0, 1);
+        assert_eq!(
+            check_signatures(msg_hash, &[0, 1], 42, &wrong_apk, &state),
+            Err(SignatureCheckError::QuorumApkMismatch(0))
+        );
+    }
+}
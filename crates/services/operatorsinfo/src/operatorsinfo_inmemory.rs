@@ -2,13 +2,12 @@ use alloy_primitives::Address;
 use alloy_provider::{Provider, ProviderBuilder, WsConnect};
 use alloy_rpc_types::Filter;
 use anyhow::Result;
-use eigen_client_avsregistry::{
-    reader::AvsRegistryChainReader, subscriber::AvsRegistryChainSubscriber,
-};
+use eigen_client_avsregistry::{reader::AvsRegistryReader, subscriber::AvsRegistryChainSubscriber};
 use eigen_types::operator::{operator_id_from_g1_pub_key, OperatorPubKeys};
 use eigen_utils::binding::BLSApkRegistry::{self, G1Point, G2Point};
 use futures_util::StreamExt;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::{
     mpsc,
     mpsc::UnboundedSender,
@@ -18,7 +17,7 @@ use tokio::sync::{
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct OperatorInfoServiceInMemory {
-    avs_registry_reader: AvsRegistryChainReader,
+    avs_registry_reader: Arc<dyn AvsRegistryReader>,
     avs_registry_subscriber: AvsRegistryChainSubscriber,
     ws: String,
     pub_keys: UnboundedSender<OperatorsInfoMessage>,
@@ -35,7 +34,7 @@ enum OperatorsInfoMessage {
 impl OperatorInfoServiceInMemory {
     pub async fn new(
         avs_registry_subscriber: AvsRegistryChainSubscriber,
-        avs_registry_chain_reader: AvsRegistryChainReader,
+        avs_registry_reader: Arc<dyn AvsRegistryReader>,
         web_socket: String,
     ) -> Self {
         let (pubkeys_tx, mut pubkeys_rx) = mpsc::unbounded_channel();
@@ -63,7 +62,7 @@ impl OperatorInfoServiceInMemory {
         });
 
         Self {
-            avs_registry_reader: avs_registry_chain_reader,
+            avs_registry_reader,
             avs_registry_subscriber,
             ws: web_socket,
             pub_keys: pubkeys_tx,
@@ -136,3 +135,54 @@ impl OperatorInfoServiceInMemory {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, U256};
+    use eigen_client_mocks::fake_avs_registry::{FakeAvsRegistry, FakeOperator};
+
+    #[tokio::test]
+    async fn test_query_past_registered_operators() {
+        let operator_address = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+        let pub_keys = OperatorPubKeys {
+            g1_pub_key: G1Point {
+                X: U256::from(1),
+                Y: U256::from(2),
+            },
+            g2_pub_key: G2Point {
+                X: [U256::from(3), U256::from(4)],
+                Y: [U256::from(5), U256::from(6)],
+            },
+        };
+        let avs_registry = FakeAvsRegistry::new(1);
+        avs_registry.set_current_block(100);
+        avs_registry.register_operator(
+            FakeOperator {
+                address: operator_address,
+                operator_id: operator_id_from_g1_pub_key(pub_keys.g1_pub_key.clone()).into(),
+                pub_keys: pub_keys.clone(),
+                socket: "operator:8080".to_string(),
+            },
+            &[(0, 1000)],
+        );
+
+        let service = OperatorInfoServiceInMemory::new(
+            AvsRegistryChainSubscriber::new(
+                "http://localhost:8545".to_string(),
+                "ws://localhost:8546".to_string(),
+            ),
+            Arc::new(avs_registry),
+            "ws://localhost:8546".to_string(),
+        )
+        .await;
+        service
+            .query_past_registered_operator_events_and_fill_db(0, 0)
+            .await;
+
+        let info = service.get_operator_info(operator_address).await.unwrap();
+        assert_eq!(info.g1_pub_key.X, pub_keys.g1_pub_key.X);
+        assert_eq!(info.g2_pub_key.Y, pub_keys.g2_pub_key.Y);
+        assert!(service.get_operator_info(Address::ZERO).await.is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::FixedBytes;
+use alloy_primitives::{FixedBytes, U256};
 use eigen_crypto_bls::attestation::Signature;
 
 pub type TaskIndex = u32;
@@ -13,3 +13,27 @@ pub struct SignedTaskResponseDigest {
 
     pub operator_id: FixedBytes<32>,
 }
+
+/// Result of `BLSSignatureChecker.checkSignatures` for a signed message
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuorumStakeTotals {
+    /// Checked quorums, in the order of the quorum numbers of the call
+    pub quorum_numbers: Vec<u8>,
+    /// Stake of the signers in each quorum
+    pub signed_stake_for_quorum: Vec<U256>,
+    /// Total stake of each quorum at the reference block
+    pub total_stake_for_quorum: Vec<U256>,
+    /// Hash of the reference block number and the non-signer operator ids
+    pub signatory_record_hash: FixedBytes<32>,
+}
+
+impl QuorumStakeTotals {
+    /// Quorum number, signed stake and total stake of each checked quorum
+    pub fn iter(&self) -> impl Iterator<Item = (u8, U256, U256)> + '_ {
+        self.quorum_numbers
+            .iter()
+            .zip(&self.signed_stake_for_quorum)
+            .zip(&self.total_stake_for_quorum)
+            .map(|((quorum, signed), total)| (*quorum, *signed, *total))
+    }
+}
@@ -5,4 +5,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 pub mod avs;
+pub mod metadata;
 pub mod operator;
+pub mod quorum;
+pub mod socket;
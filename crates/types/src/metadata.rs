@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Maximum length of the name of an AVS
+const MAX_NAME_LEN: usize = 200;
+
+/// Maximum length of the description of an AVS
+const MAX_DESCRIPTION_LEN: usize = 500;
+
+/// Metadata of an AVS, the JSON document its metadata URI points to.
+///
+/// Follows the format EigenLayer expects for `updateAVSMetadataURI`: the frontends
+/// reject documents missing a field, and logos that are not PNG images.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvsMetadata {
+    /// Display name of the AVS
+    pub name: String,
+    /// Website of the AVS, an http(s) URL
+    pub website: String,
+    /// Description of the AVS
+    pub description: String,
+    /// Logo of the AVS, an http(s) URL of a PNG image
+    pub logo: String,
+    /// Twitter profile of the AVS, an http(s) URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub twitter: Option<String>,
+}
+
+/// Reason an AVS metadata document is invalid
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    /// Document is not a JSON object with the metadata fields
+    #[error("invalid metadata json")]
+    InvalidJson(#[from] serde_json::Error),
+
+    /// Name is empty
+    #[error("metadata name is empty")]
+    EmptyName,
+
+    /// Name is longer than 200 characters
+    #[error("metadata name is longer than {MAX_NAME_LEN} characters")]
+    NameTooLong,
+
+    /// Description is empty
+    #[error("metadata description is empty")]
+    EmptyDescription,
+
+    /// Description is longer than 500 characters
+    #[error("metadata description is longer than {MAX_DESCRIPTION_LEN} characters")]
+    DescriptionTooLong,
+
+    /// Field is not an http(s) URL
+    #[error("metadata {field} {url:?} is not an http(s) url")]
+    InvalidUrl {
+        /// Name of the field
+        field: &'static str,
+        /// Value of the field
+        url: String,
+    },
+
+    /// Logo is not a PNG image
+    #[error("metadata logo {0:?} is not a png image")]
+    LogoNotPng(String),
+}
+
+impl AvsMetadata {
+    /// Checks the fields are what EigenLayer accepts
+    pub fn validate(&self) -> Result<(), MetadataError> {
+        if self.name.trim().is_empty() {
+            return Err(MetadataError::EmptyName);
+        }
+        if self.name.chars().count() > MAX_NAME_LEN {
+            return Err(MetadataError::NameTooLong);
+        }
+        if self.description.trim().is_empty() {
+            return Err(MetadataError::EmptyDescription);
+        }
+        if self.description.chars().count() > MAX_DESCRIPTION_LEN {
+            return Err(MetadataError::DescriptionTooLong);
+        }
+        check_url("website", &self.website)?;
+        check_url("logo", &self.logo)?;
+        if !self.logo.to_ascii_lowercase().ends_with(".png") {
+            return Err(MetadataError::LogoNotPng(self.logo.clone()));
+        }
+        if let Some(twitter) = &self.twitter {
+            check_url("twitter", twitter)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AvsMetadata {
+    type Err = MetadataError;
+
+    /// Parses and validates a metadata JSON document
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        let metadata: AvsMetadata = serde_json::from_str(json)?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+}
+
+fn check_url(field: &'static str, url: &str) -> Result<(), MetadataError> {
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next());
+    match host {
+        Some(host) if !host.is_empty() && !url.contains(char::is_whitespace) => Ok(()),
+        _ => Err(MetadataError::InvalidUrl {
+            field,
+            url: url.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"{
+        "name": "Incredible Squaring",
+        "website": "https://www.eigenlayer.xyz",
+        "description": "Squares numbers",
+        "logo": "https://www.eigenlayer.xyz/logo.png",
+        "twitter": "https://x.com/eigenlayer"
+    }"#;
+
+    #[test]
+    fn test_parse_metadata() {
+        let metadata: AvsMetadata = METADATA.parse().unwrap();
+        assert_eq!(metadata.name, "Incredible Squaring");
+        assert_eq!(
+            metadata.twitter.as_deref(),
+            Some("https://x.com/eigenlayer")
+        );
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(json.parse::<AvsMetadata>().unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_invalid_metadata() {
+        let metadata: AvsMetadata = METADATA.parse().unwrap();
+        let validate = |f: fn(&mut AvsMetadata)| {
+            let mut metadata = metadata.clone();
+            f(&mut metadata);
+            metadata.validate().unwrap_err()
+        };
+
+        assert!(matches!(
+            validate(|m| m.name = " ".into()),
+            MetadataError::EmptyName
+        ));
+        assert!(matches!(
+            validate(|m| m.name = "a".repeat(201)),
+            MetadataError::NameTooLong
+        ));
+        assert!(matches!(
+            validate(|m| m.description = String::new()),
+            MetadataError::EmptyDescription
+        ));
+        assert!(matches!(
+            validate(|m| m.description = "a".repeat(501)),
+            MetadataError::DescriptionTooLong
+        ));
+        assert!(matches!(
+            validate(|m| m.website = "www.eigenlayer.xyz".into()),
+            MetadataError::InvalidUrl {
+                field: "website",
+                ..
+            }
+        ));
+        assert!(matches!(
+            validate(|m| m.logo = "https://www.eigenlayer.xyz/logo.svg".into()),
+            MetadataError::LogoNotPng(_)
+        ));
+        assert!(matches!(
+            validate(|m| m.twitter = Some("https:// x.com".into())),
+            MetadataError::InvalidUrl {
+                field: "twitter",
+                ..
+            }
+        ));
+        assert!(matches!(
+            r#"{"name": "AVS"}"#.parse::<AvsMetadata>(),
+            Err(MetadataError::InvalidJson(_))
+        ));
+    }
+}
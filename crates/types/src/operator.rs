@@ -1,8 +1,8 @@
-use alloy_primitives::{Address, FixedBytes, U256};
-use eigen_utils::binding::BLSApkRegistry;
+use alloy_primitives::{Address, FixedBytes, TxHash, U256};
+use eigen_utils::binding::{BLSApkRegistry, OperatorStateRetriever};
 use ethers::{types::U64, utils::keccak256};
 use num_bigint::BigUint;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 const MAX_NUMBER_OF_QUORUMS: usize = 192;
 use BLSApkRegistry::{G1Point, G2Point};
 
@@ -96,7 +96,7 @@ impl Operator {
 
 pub type Socket = String;
 
-type QuorumNum = u8;
+pub type QuorumNum = u8;
 
 pub struct OperatorInfo {
     pub pub_keys: Option<OperatorPubKeys>,
@@ -123,6 +123,187 @@ pub struct QuorumAvsState {
     pub block_num: u32,
 }
 
+/// Stake of an operator in a quorum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorStake {
+    pub operator: Address,
+    pub operator_id: OperatorId,
+    pub quorum: QuorumNum,
+    pub stake: U256,
+}
+
+/// Stake of an operator, or total stake of a quorum, from `update_block_number`
+/// until `next_update_block_number`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeUpdate {
+    pub update_block_number: u32,
+    /// Block of the next update, `None` for the latest update
+    pub next_update_block_number: Option<u32>,
+    pub stake: U256,
+}
+
+impl StakeUpdate {
+    /// Whether this update holds the stake at `block_number`
+    pub fn is_active_at(&self, block_number: u32) -> bool {
+        self.update_block_number <= block_number
+            && self
+                .next_update_block_number
+                .map_or(true, |next| block_number < next)
+    }
+}
+
+/// Whether an operator registered to or deregistered from the AVS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrationKind {
+    Registered,
+    Deregistered,
+}
+
+/// `OperatorRegistered` or `OperatorDeregistered` event of the RegistryCoordinator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorRegistrationEvent {
+    pub kind: RegistrationKind,
+    pub operator: Address,
+    pub operator_id: OperatorId,
+    pub block_number: u64,
+    pub transaction_hash: TxHash,
+}
+
+/// `OperatorStakeUpdate` event of the StakeRegistry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorStakeUpdateEvent {
+    pub operator_id: OperatorId,
+    pub quorum: QuorumNum,
+    pub stake: U256,
+    pub block_number: u64,
+    pub transaction_hash: TxHash,
+}
+
+/// Operators registered in each quorum with their stake, keyed by quorum number
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuorumOperatorSet {
+    quorums: BTreeMap<QuorumNum, Vec<OperatorStake>>,
+}
+
+impl QuorumOperatorSet {
+    /// Operator set of the `OperatorStateRetriever.getOperatorState` result, whose
+    /// i-th list holds the operators of `quorum_numbers[i]`
+    pub fn from_operator_state(
+        quorum_numbers: &[QuorumNum],
+        operator_state: Vec<Vec<OperatorStateRetriever::Operator>>,
+    ) -> Self {
+        let mut set = Self::default();
+        for (quorum, operators) in quorum_numbers.iter().zip(operator_state) {
+            set.insert(
+                *quorum,
+                operators
+                    .into_iter()
+                    .map(|operator| OperatorStake {
+                        operator: operator.operator,
+                        operator_id: operator.operatorId,
+                        quorum: *quorum,
+                        stake: U256::from(operator.stake),
+                    })
+                    .collect(),
+            );
+        }
+        set
+    }
+
+    /// Set the operators of `quorum`, replacing the previous ones
+    pub fn insert(&mut self, quorum: QuorumNum, operators: Vec<OperatorStake>) {
+        self.quorums.insert(quorum, operators);
+    }
+
+    /// Quorum numbers of the set, in increasing order
+    pub fn quorums(&self) -> impl Iterator<Item = QuorumNum> + '_ {
+        self.quorums.keys().copied()
+    }
+
+    /// Operators of `quorum`, empty if the quorum is not in the set
+    pub fn operators(&self, quorum: QuorumNum) -> &[OperatorStake] {
+        self.quorums.get(&quorum).map_or(&[], Vec::as_slice)
+    }
+
+    /// Stake of `operator_id` in `quorum`, if it is in the quorum
+    pub fn stake(&self, quorum: QuorumNum, operator_id: OperatorId) -> Option<U256> {
+        self.operators(quorum)
+            .iter()
+            .find(|operator| operator.operator_id == operator_id)
+            .map(|operator| operator.stake)
+    }
+
+    /// Total stake of the operators of `quorum`
+    pub fn total_stake(&self, quorum: QuorumNum) -> U256 {
+        self.operators(quorum)
+            .iter()
+            .fold(U256::ZERO, |total, operator| total + operator.stake)
+    }
+
+    /// Stakes of all quorums, ordered by quorum number
+    pub fn iter(&self) -> impl Iterator<Item = &OperatorStake> {
+        self.quorums.values().flatten()
+    }
+
+    /// Number of quorums in the set
+    pub fn len(&self) -> usize {
+        self.quorums.len()
+    }
+
+    /// Whether the set has no quorum
+    pub fn is_empty(&self) -> bool {
+        self.quorums.is_empty()
+    }
+}
+
 pub type QuorumThresholdPercentage = u8;
 
 pub type QuorumThresholdPercentages = Vec<QuorumThresholdPercentage>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quorum_operator_set_from_operator_state() {
+        let operator = |id: u8, stake: u128| OperatorStateRetriever::Operator {
+            operator: Address::repeat_byte(id),
+            operatorId: FixedBytes::repeat_byte(id),
+            stake,
+        };
+        let set = QuorumOperatorSet::from_operator_state(
+            &[3, 1],
+            vec![vec![operator(1, 10), operator(2, 20)], vec![operator(2, 5)]],
+        );
+
+        assert_eq!(set.quorums().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(set.operators(3).len(), 2);
+        assert_eq!(set.operators(3)[1].quorum, 3);
+        assert_eq!(set.operators(0), &[]);
+        assert_eq!(set.total_stake(3), U256::from(30));
+        assert_eq!(
+            set.stake(1, FixedBytes::repeat_byte(2)),
+            Some(U256::from(5))
+        );
+        assert_eq!(set.stake(1, FixedBytes::repeat_byte(1)), None);
+        assert_eq!(set.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_stake_update_is_active_at() {
+        let update = StakeUpdate {
+            update_block_number: 10,
+            next_update_block_number: Some(20),
+            stake: U256::from(1),
+        };
+        assert!(!update.is_active_at(9));
+        assert!(update.is_active_at(10));
+        assert!(!update.is_active_at(20));
+
+        let latest = StakeUpdate {
+            next_update_block_number: None,
+            ..update
+        };
+        assert!(latest.is_active_at(u32::MAX));
+    }
+}
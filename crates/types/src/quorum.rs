@@ -0,0 +1,145 @@
+use crate::operator::{OperatorStake, QuorumNum};
+use alloy_primitives::{Address, U256};
+use eigen_utils::binding::RegistryCoordinator::OperatorSetParam;
+
+/// Strategy counted in the stake of a quorum, with its weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyParams {
+    pub strategy: Address,
+    /// Multiplier of the shares of the strategy, in units of 1e18
+    pub multiplier: U256,
+}
+
+/// Operator cap of a quorum and the stake thresholds for churning operators out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperatorSetParams {
+    pub max_operator_count: u32,
+    /// Stake, in BIPs of the stake of the churned operator, an incoming operator needs
+    pub kick_bips_of_operator_stake: u16,
+    /// Stake, in BIPs of the total quorum stake, below which an operator can be churned
+    pub kick_bips_of_total_stake: u16,
+}
+
+impl From<OperatorSetParam> for OperatorSetParams {
+    fn from(params: OperatorSetParam) -> Self {
+        Self {
+            max_operator_count: params.maxOperatorCount,
+            kick_bips_of_operator_stake: params.kickBIPsOfOperatorStake,
+            kick_bips_of_total_stake: params.kickBIPsOfTotalStake,
+        }
+    }
+}
+
+impl From<OperatorSetParams> for OperatorSetParam {
+    fn from(params: OperatorSetParams) -> Self {
+        Self {
+            maxOperatorCount: params.max_operator_count,
+            kickBIPsOfOperatorStake: params.kick_bips_of_operator_stake,
+            kickBIPsOfTotalStake: params.kick_bips_of_total_stake,
+        }
+    }
+}
+
+/// Denominator of the kick BIPs of [`OperatorSetParams`]
+pub const BIPS_DENOMINATOR: u16 = 10_000;
+
+impl OperatorSetParams {
+    /// Whether a quorum with `operator_count` operators requires churning one out to
+    /// register another
+    pub fn is_full(&self, operator_count: usize) -> bool {
+        operator_count >= self.max_operator_count as usize
+    }
+
+    /// Lowest stake operator of `operators`, the operators of a full quorum, that an
+    /// operator with `new_operator_stake` can churn out, if any.
+    ///
+    /// Mirrors the checks of `RegistryCoordinator._validateChurn`: the new operator
+    /// stake must exceed `kick_bips_of_operator_stake` of the churned operator stake,
+    /// and the churned operator stake must be below `kick_bips_of_total_stake` of the
+    /// quorum stake including the new operator.
+    pub fn operator_to_kick<'a>(
+        &self,
+        operators: &'a [OperatorStake],
+        new_operator_stake: U256,
+    ) -> Option<&'a OperatorStake> {
+        let lowest = operators.iter().min_by_key(|operator| operator.stake)?;
+        let total_stake = operators
+            .iter()
+            .fold(new_operator_stake, |total, operator| total + operator.stake);
+        let bips = U256::from(BIPS_DENOMINATOR);
+        let individual_threshold =
+            lowest.stake * U256::from(self.kick_bips_of_operator_stake) / bips;
+        let total_threshold = total_stake * U256::from(self.kick_bips_of_total_stake) / bips;
+        (new_operator_stake > individual_threshold && lowest.stake < total_threshold)
+            .then_some(lowest)
+    }
+}
+
+/// Configuration of a quorum across the RegistryCoordinator and the StakeRegistry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuorumConfig {
+    pub quorum_number: QuorumNum,
+    pub strategies: Vec<StrategyParams>,
+    pub minimum_stake: U256,
+    pub operator_set_params: OperatorSetParams,
+    /// Address signing the churn approvals, shared by all quorums
+    pub churn_approver: Address,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::FixedBytes;
+
+    fn operator(id: u8, stake: u64) -> OperatorStake {
+        OperatorStake {
+            operator: Address::repeat_byte(id),
+            operator_id: FixedBytes::repeat_byte(id),
+            quorum: 0,
+            stake: U256::from(stake),
+        }
+    }
+
+    #[test]
+    fn test_operator_to_kick() {
+        let params = OperatorSetParams {
+            max_operator_count: 3,
+            kick_bips_of_operator_stake: 15_000,
+            kick_bips_of_total_stake: 2_000,
+        };
+        let operators = [operator(1, 300), operator(2, 100), operator(3, 500)];
+        assert!(params.is_full(operators.len()));
+        assert!(!params.is_full(2));
+
+        // 151 > 100 * 1.5 and 100 < (900 + 151) * 0.2
+        assert_eq!(
+            params.operator_to_kick(&operators, U256::from(151)),
+            Some(&operators[1])
+        );
+        // 150 is not above 100 * 1.5
+        assert_eq!(params.operator_to_kick(&operators, U256::from(150)), None);
+
+        // 100 is not below (1000 + 200) * 0.05
+        let params = OperatorSetParams {
+            kick_bips_of_total_stake: 500,
+            ..params
+        };
+        let operators = [operator(1, 900), operator(2, 100)];
+        assert_eq!(params.operator_to_kick(&operators, U256::from(200)), None);
+        assert_eq!(params.operator_to_kick(&[], U256::from(200)), None);
+    }
+
+    #[test]
+    fn test_operator_set_params_round_trip() {
+        let params = OperatorSetParams {
+            max_operator_count: 10,
+            kick_bips_of_operator_stake: 15_000,
+            kick_bips_of_total_stake: 100,
+        };
+        let param = OperatorSetParam::from(params);
+        assert_eq!(param.maxOperatorCount, 10);
+        assert_eq!(param.kickBIPsOfOperatorStake, 15_000);
+        assert_eq!(param.kickBIPsOfTotalStake, 100);
+        assert_eq!(OperatorSetParams::from(param), params);
+    }
+}
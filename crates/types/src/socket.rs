@@ -0,0 +1,188 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Maximum length of a DNS hostname
+const MAX_HOSTNAME_LEN: usize = 253;
+
+/// Maximum length of a label of a DNS hostname
+const MAX_LABEL_LEN: usize = 63;
+
+/// Socket an operator registers with the registry coordinator, where the AVS reaches it.
+///
+/// Either `host:port`, or `host:port;port...` for AVSs whose operators serve several
+/// endpoints on the same host (e.g. the dispersal and retrieval ports of EigenDA). The
+/// host is a hostname, an IPv4 address or a bracketed IPv6 address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OperatorSocket {
+    host: String,
+    ports: Vec<u16>,
+}
+
+/// Reason a socket is malformed
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SocketError {
+    /// Socket has no `:` between the host and the ports
+    #[error("socket {0:?} is not of the form host:port")]
+    MissingPort(String),
+
+    /// Host is not a hostname, an IPv4 address or a bracketed IPv6 address
+    #[error("invalid socket host {0:?}")]
+    InvalidHost(String),
+
+    /// Port is not a number between 1 and 65535
+    #[error("invalid socket port {0:?}")]
+    InvalidPort(String),
+}
+
+impl OperatorSocket {
+    /// Socket of `host` serving `ports`, in order. IPv6 hosts are given without
+    /// brackets.
+    pub fn new(host: impl Into<String>, ports: Vec<u16>) -> Result<Self, SocketError> {
+        let host = host.into();
+        if !is_valid_host(&host) {
+            return Err(SocketError::InvalidHost(host));
+        }
+        match ports.first() {
+            None => Err(SocketError::MissingPort(host)),
+            Some(_) if ports.contains(&0) => Err(SocketError::InvalidPort("0".to_string())),
+            Some(_) => Ok(OperatorSocket { host, ports }),
+        }
+    }
+
+    /// Host of the socket, without brackets for IPv6 addresses
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Ports of the socket, the first being its main endpoint
+    pub fn ports(&self) -> &[u16] {
+        &self.ports
+    }
+
+    /// Main port of the socket
+    pub fn port(&self) -> u16 {
+        self.ports[0]
+    }
+}
+
+impl FromStr for OperatorSocket {
+    type Err = SocketError;
+
+    fn from_str(socket: &str) -> Result<Self, Self::Err> {
+        let (host, ports) = match socket.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, ports) = bracketed
+                    .split_once("]:")
+                    .ok_or_else(|| SocketError::MissingPort(socket.to_string()))?;
+                if host.parse::<Ipv6Addr>().is_err() {
+                    return Err(SocketError::InvalidHost(host.to_string()));
+                }
+                (host, ports)
+            }
+            None => {
+                let (host, ports) = socket
+                    .rsplit_once(':')
+                    .ok_or_else(|| SocketError::MissingPort(socket.to_string()))?;
+                // IPv6 addresses must be bracketed to be told apart from the port
+                if host.contains(':') {
+                    return Err(SocketError::InvalidHost(host.to_string()));
+                }
+                (host, ports)
+            }
+        };
+        let ports = ports
+            .split(';')
+            .map(|port| {
+                port.bytes()
+                    .all(|b| b.is_ascii_digit())
+                    .then(|| port.parse::<u16>().ok())
+                    .flatten()
+                    .filter(|port| *port != 0)
+                    .ok_or_else(|| SocketError::InvalidPort(port.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        OperatorSocket::new(host, ports)
+    }
+}
+
+impl fmt::Display for OperatorSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "[{}]", self.host)?;
+        } else {
+            write!(f, "{}", self.host)?;
+        }
+        for (i, port) in self.ports.iter().enumerate() {
+            let separator = if i == 0 { ':' } else { ';' };
+            write!(f, "{separator}{port}")?;
+        }
+        Ok(())
+    }
+}
+
+fn is_valid_host(host: &str) -> bool {
+    if host.parse::<Ipv4Addr>().is_ok() || host.parse::<Ipv6Addr>().is_ok() {
+        return true;
+    }
+    !host.is_empty()
+        && host.len() <= MAX_HOSTNAME_LEN
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_LABEL_LEN
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_socket() {
+        let socket: OperatorSocket = "operator.example.com:32005".parse().unwrap();
+        assert_eq!(socket.host(), "operator.example.com");
+        assert_eq!(socket.ports(), &[32005]);
+
+        let socket: OperatorSocket = "10.0.0.1:32005;32004".parse().unwrap();
+        assert_eq!(socket.host(), "10.0.0.1");
+        assert_eq!(socket.port(), 32005);
+        assert_eq!(socket.ports(), &[32005, 32004]);
+        assert_eq!(socket.to_string(), "10.0.0.1:32005;32004");
+
+        let socket: OperatorSocket = "[2001:db8::1]:9000".parse().unwrap();
+        assert_eq!(socket.host(), "2001:db8::1");
+        assert_eq!(socket.to_string(), "[2001:db8::1]:9000");
+    }
+
+    #[test]
+    fn test_parse_malformed_socket() {
+        let parse = |socket: &str| socket.parse::<OperatorSocket>().unwrap_err();
+
+        assert_eq!(
+            parse("localhost"),
+            SocketError::MissingPort("localhost".into())
+        );
+        assert_eq!(parse(":8080"), SocketError::InvalidHost("".into()));
+        assert_eq!(
+            parse("http://host:8080"),
+            SocketError::InvalidHost("http://host".into())
+        );
+        assert_eq!(
+            parse("2001:db8::1:80"),
+            SocketError::InvalidHost("2001:db8::1".into())
+        );
+        assert_eq!(parse("[host]:80"), SocketError::InvalidHost("host".into()));
+        assert_eq!(parse("host:-1"), SocketError::InvalidPort("-1".into()));
+        assert_eq!(parse("host:+80"), SocketError::InvalidPort("+80".into()));
+        assert_eq!(
+            parse("host:65536"),
+            SocketError::InvalidPort("65536".into())
+        );
+        assert_eq!(parse("host:80;"), SocketError::InvalidPort("".into()));
+        assert_eq!(parse("host:0"), SocketError::InvalidPort("0".into()));
+    }
+}
@@ -4,6 +4,7 @@ use alloy_sol_types::sol;
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     StakeRegistry,
     "../../crates/contracts/bindings/utils/json/StakeRegistry.json"
 );
@@ -13,6 +14,7 @@ sol!(
     #[allow(clippy::too_many_arguments)]
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     RegistryCoordinator,
     "../../crates/contracts/bindings/utils/json/RegistryCoordinator.json"
 );
@@ -47,6 +49,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     AVSDirectory,
     "../../crates/contracts/bindings/utils/json/AVSDirectory.json"
 );
@@ -55,6 +58,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     DelegationManager,
     "../../crates/contracts/bindings/utils/json/DelegationManager.json"
 );
@@ -79,6 +83,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     IERC20,
     "../../crates/contracts/bindings/utils/json/IERC20.json"
 );
@@ -87,6 +92,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     StrategyManager,
     "../../crates/contracts/bindings/utils/json/StrategyManager.json"
 );
@@ -107,6 +113,15 @@ sol!(
     "../../crates/contracts/bindings/utils/json/ECDSAStakeRegistry.json"
 );
 
+// https://github.com/Layr-Labs/eigenlayer-middleware/blob/m2-mainnet/src/interfaces/IBLSSignatureChecker.sol
+sol!(
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    #[derive(Debug)]
+    IBLSSignatureChecker,
+    "../../crates/contracts/bindings/utils/json/IBLSSignatureChecker.json"
+);
+
 // Anvil utilities
 
 sol!(
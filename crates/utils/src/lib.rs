@@ -5,6 +5,8 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 pub mod binding;
+pub mod log_scanner;
+pub mod revert;
 use alloy_network::{Ethereum, EthereumWallet};
 use alloy_provider::{
     fillers::{ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller, WalletFiller},
@@ -0,0 +1,328 @@
+//! Paged `eth_getLogs` queries over block ranges larger than providers accept.
+use alloy_provider::Provider;
+use alloy_rpc_types::{Filter, Log};
+use alloy_transport::{Transport, TransportError};
+use futures_util::future::join_all;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Default number of blocks queried by each `eth_getLogs` request
+pub const DEFAULT_CHUNK_SIZE: u64 = 10_000;
+
+/// JSON-RPC error codes providers reject a query for its block range or its number of
+/// results with: limit exceeded, invalid params, invalid request and server error
+const RANGE_ERROR_CODES: [i64; 4] = [-32005, -32602, -32600, -32000];
+
+/// Parts of the error messages of providers rejecting a query for its block range or its
+/// number of results, e.g. "query returned more than 10000 results" (Infura), "Log
+/// response size exceeded" (Alchemy), "eth_getLogs is limited to a 10,000 range"
+/// (QuickNode) or "exceed maximum block range: 5000" (Geth based nodes)
+const RANGE_ERRORS: [&str; 5] = [
+    "query returned more than",
+    "log response size exceeded",
+    "getlogs is limited to",
+    "block range",
+    "exceeds max results",
+];
+
+/// Progress of a scan, reported after each scanned chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// First block of the scanned chunk
+    pub from_block: u64,
+    /// Last block of the scanned chunk
+    pub to_block: u64,
+    /// Number of blocks scanned so far
+    pub scanned_blocks: u64,
+    /// Number of blocks of the scan
+    pub total_blocks: u64,
+}
+
+type ProgressCallback = Arc<dyn Fn(ScanProgress) + Send + Sync>;
+
+/// Scanner of the logs matching a filter over a block range, queried in chunks.
+///
+/// Chunks start at [`LogScanner::with_chunk_size`] blocks. When the provider rejects a
+/// chunk because its range is too large or it has too many results, the chunk is split
+/// in two and the following chunks use the smaller size.
+#[derive(Clone)]
+pub struct LogScanner {
+    chunk_size: u64,
+    concurrency: usize,
+    progress: Option<ProgressCallback>,
+}
+
+impl Default for LogScanner {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            concurrency: 1,
+            progress: None,
+        }
+    }
+}
+
+impl fmt::Debug for LogScanner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogScanner")
+            .field("chunk_size", &self.chunk_size)
+            .field("concurrency", &self.concurrency)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl LogScanner {
+    /// Scanner with the default chunk size, querying one chunk at a time
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of blocks of the first chunks
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Set the number of chunks queried concurrently
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Call `progress` after each scanned chunk
+    pub fn with_progress(
+        mut self,
+        progress: impl Fn(ScanProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Logs matching `filter` from `from_block` to `to_block` included, in block order.
+    ///
+    /// The block range of `filter` is ignored.
+    pub async fn scan<P, T>(
+        &self,
+        provider: &P,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>, TransportError>
+    where
+        P: Provider<T>,
+        T: Transport + Clone,
+    {
+        self.scan_with(from_block, to_block, |from, to| {
+            let filter = filter.clone().from_block(from).to_block(to);
+            async move { provider.get_logs(&filter).await }
+        })
+        .await
+    }
+
+    async fn scan_with<L, F, Fut>(
+        &self,
+        from_block: u64,
+        to_block: u64,
+        fetch: F,
+    ) -> Result<Vec<L>, TransportError>
+    where
+        F: Fn(u64, u64) -> Fut,
+        Fut: Future<Output = Result<Vec<L>, TransportError>>,
+    {
+        if from_block > to_block {
+            return Ok(Vec::new());
+        }
+        let total_blocks = to_block - from_block + 1;
+        let mut chunk_size = self.chunk_size;
+        let mut next_block = Some(from_block);
+        // split chunks to query again, lowest last
+        let mut retries: Vec<(u64, u64)> = Vec::new();
+        let mut chunks = BTreeMap::new();
+        let mut scanned_blocks = 0;
+
+        loop {
+            let mut batch = Vec::with_capacity(self.concurrency);
+            while batch.len() < self.concurrency {
+                if let Some(chunk) = retries.pop() {
+                    batch.push(chunk);
+                } else if let Some(from) = next_block {
+                    let to = from.saturating_add(chunk_size - 1).min(to_block);
+                    next_block = (to < to_block).then_some(to + 1);
+                    batch.push((from, to));
+                } else {
+                    break;
+                }
+            }
+            if batch.is_empty() {
+                break;
+            }
+
+            let results = join_all(batch.iter().map(|(from, to)| fetch(*from, *to))).await;
+            for ((from, to), result) in batch.into_iter().zip(results) {
+                match result {
+                    Ok(logs) => {
+                        scanned_blocks += to - from + 1;
+                        chunks.insert(from, logs);
+                        if let Some(progress) = &self.progress {
+                            progress(ScanProgress {
+                                from_block: from,
+                                to_block: to,
+                                scanned_blocks,
+                                total_blocks,
+                            });
+                        }
+                    }
+                    Err(err) if from < to && is_range_error(&err) => {
+                        let mid = from + (to - from) / 2;
+                        chunk_size = chunk_size.min(mid - from + 1);
+                        retries.push((mid + 1, to));
+                        retries.push((from, mid));
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        Ok(chunks.into_values().flatten().collect())
+    }
+}
+
+/// Whether the provider rejected the query for its block range or number of results
+fn is_range_error(err: &TransportError) -> bool {
+    err.as_error_resp().is_some_and(|payload| {
+        let message = payload.message.to_lowercase();
+        RANGE_ERROR_CODES.contains(&payload.code)
+            && RANGE_ERRORS.iter().any(|part| message.contains(part))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_transport::RpcError;
+    use std::sync::Mutex;
+
+    fn error_resp(code: i64, message: &str) -> TransportError {
+        RpcError::ErrorResp(ErrorPayload {
+            code,
+            message: message.to_string(),
+            data: None,
+        })
+    }
+
+    /// Fetch of one log per block, rejecting ranges of more than `max_range` blocks
+    fn fetch(
+        max_range: u64,
+        queries: &Mutex<Vec<(u64, u64)>>,
+    ) -> impl Fn(u64, u64) -> std::future::Ready<Result<Vec<u64>, TransportError>> + '_ {
+        move |from, to| {
+            queries.lock().unwrap().push((from, to));
+            std::future::ready(if to - from + 1 > max_range {
+                Err(error_resp(-32005, "query returned more than 10000 results"))
+            } else {
+                Ok((from..=to).collect())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_splits_rejected_chunks() {
+        let queries = Mutex::new(Vec::new());
+        let logs = LogScanner::new()
+            .with_chunk_size(100)
+            .scan_with(10, 259, fetch(40, &queries))
+            .await
+            .unwrap();
+
+        assert_eq!(logs, (10..=259).collect::<Vec<_>>());
+        let queries = queries.into_inner().unwrap();
+        assert_eq!(&queries[..4], [(10, 109), (10, 59), (10, 34), (35, 59)]);
+        // the chunks after the split ones use the reduced size
+        assert!(queries
+            .iter()
+            .filter(|(from, _)| *from >= 110)
+            .all(|(from, to)| to - from + 1 == 25 || *to == 259));
+    }
+
+    #[tokio::test]
+    async fn test_scan_concurrent_chunks_in_order() {
+        let queries = Mutex::new(Vec::new());
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let reported = progress.clone();
+        let logs = LogScanner::new()
+            .with_chunk_size(7)
+            .with_concurrency(3)
+            .with_progress(move |p| reported.lock().unwrap().push(p.scanned_blocks))
+            .scan_with(0, 49, fetch(7, &queries))
+            .await
+            .unwrap();
+
+        assert_eq!(logs, (0..50).collect::<Vec<_>>());
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 8);
+        assert_eq!(progress.last(), Some(&50));
+    }
+
+    #[tokio::test]
+    async fn test_scan_returns_other_errors() {
+        let scanner = LogScanner::new().with_chunk_size(10);
+        let err = scanner
+            .scan_with(0, 100, |_, _| {
+                std::future::ready(Err::<Vec<u64>, _>(error_resp(-32000, "nonce too low")))
+            })
+            .await
+            .unwrap_err();
+        assert!(!is_range_error(&err));
+
+        let single_block = scanner
+            .scan_with(5, 5, |_, _| {
+                std::future::ready(Err::<Vec<u64>, _>(error_resp(
+                    -32000,
+                    "block range too large",
+                )))
+            })
+            .await;
+        assert!(single_block.is_err());
+
+        assert!(scanner
+            .scan_with(6, 5, |_, _| std::future::ready(Ok(vec![0u64])))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scan_does_not_split_on_rate_limit() {
+        let queries = Mutex::new(Vec::new());
+        let err = LogScanner::new()
+            .with_chunk_size(100)
+            .scan_with(0, 99, |from, to| {
+                queries.lock().unwrap().push((from, to));
+                std::future::ready(Err::<Vec<u64>, _>(error_resp(
+                    -32005,
+                    "daily request count exceeded, request rate limited",
+                )))
+            })
+            .await
+            .unwrap_err();
+        assert!(!is_range_error(&err));
+        assert_eq!(queries.into_inner().unwrap(), [(0, 99)]);
+
+        assert!(is_range_error(&error_resp(
+            -32602,
+            "Log response size exceeded. You can make eth_getLogs requests with up to a 2K block range"
+        )));
+        assert!(!is_range_error(&error_resp(
+            -32602,
+            "invalid argument 0: hex string without 0x prefix"
+        )));
+        assert!(!is_range_error(&error_resp(
+            -32601,
+            "block range not supported"
+        )));
+    }
+}
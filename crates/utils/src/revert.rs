@@ -0,0 +1,109 @@
+//! Revert reasons of failed contract calls and gas estimations.
+use alloy_primitives::Bytes;
+use alloy_sol_types::{ContractError, GenericRevertReason};
+use alloy_transport::TransportError;
+
+/// Prefix of the error message of nodes that do not return the revert data
+const EXECUTION_REVERTED: &str = "execution reverted: ";
+
+/// Revert reason of the call that failed with `err` or one of its sources, `None` if
+/// the failure is not a revert (e.g. a connection error or a timeout).
+///
+/// `Error(string)` reverts yield their message and `Panic(uint256)` reverts the
+/// panic description.
+pub fn revert_reason(err: &(dyn std::error::Error + 'static)) -> Option<String> {
+    transport_error(err).and_then(transport_revert_reason)
+}
+
+/// Revert data, selector included, of the call that failed with `err` or one of its
+/// sources. `None` if the failure is not a revert or the node did not return the data.
+pub fn revert_data(err: &(dyn std::error::Error + 'static)) -> Option<Bytes> {
+    transport_error(err)?
+        .as_error_resp()?
+        .try_data_as::<Bytes>()?
+        .ok()
+}
+
+/// First of `err` and its sources that is a transport error
+fn transport_error<'a>(err: &'a (dyn std::error::Error + 'static)) -> Option<&'a TransportError> {
+    let mut current = Some(err);
+    while let Some(err) = current {
+        // the transport error of a contract error is transparent, it is not a source
+        if let Some(alloy_contract::Error::TransportError(transport_err)) =
+            err.downcast_ref::<alloy_contract::Error>()
+        {
+            return Some(transport_err);
+        }
+        if let Some(transport_err) = err.downcast_ref::<TransportError>() {
+            return Some(transport_err);
+        }
+        current = err.source();
+    }
+    None
+}
+
+fn transport_revert_reason(err: &TransportError) -> Option<String> {
+    let payload = err.as_error_resp()?;
+    if let Some(Ok(data)) = payload.try_data_as::<Bytes>() {
+        match GenericRevertReason::decode(&data) {
+            Some(GenericRevertReason::ContractError(ContractError::Revert(revert))) => {
+                return Some(revert.reason)
+            }
+            Some(reason) => return Some(reason.to_string()),
+            None => {}
+        }
+    }
+    payload
+        .message
+        .strip_prefix(EXECUTION_REVERTED)
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_sol_types::{Revert, SolError};
+    use alloy_transport::RpcError;
+    use serde_json::value::RawValue;
+
+    fn error_resp(message: &str, data: Option<&str>) -> TransportError {
+        RpcError::ErrorResp(ErrorPayload {
+            code: 3,
+            message: message.to_string(),
+            data: data.map(|d| RawValue::from_string(format!("\"{d}\"")).unwrap()),
+        })
+    }
+
+    #[test]
+    fn test_revert_reason_from_revert_data() {
+        let data = Bytes::from(Revert::from("Pausable: index is paused").abi_encode());
+        let err = alloy_contract::Error::TransportError(error_resp(
+            "execution reverted",
+            Some(&data.to_string()),
+        ));
+
+        assert_eq!(
+            revert_reason(&err).as_deref(),
+            Some("Pausable: index is paused")
+        );
+        assert_eq!(revert_data(&err), Some(data));
+    }
+
+    #[test]
+    fn test_revert_reason_from_message() {
+        let err = error_resp("execution reverted: salt already spent", None);
+
+        assert_eq!(revert_reason(&err).as_deref(), Some("salt already spent"));
+    }
+
+    #[test]
+    fn test_no_revert_reason() {
+        let err = error_resp("nonce too low", None);
+        assert_eq!(revert_reason(&err), None);
+        assert_eq!(revert_data(&err), None);
+
+        let err = alloy_contract::Error::UnknownFunction("quorumCount".to_string());
+        assert_eq!(revert_reason(&err), None);
+    }
+}
@@ -1,7 +1,9 @@
 //! get operator id
 use alloy_primitives::{address, Address};
-use eigen_client_avsregistry::reader::AvsRegistryChainReader;
-use eigen_testing_utils::m2_holesky_constants::{OPERATOR_STATE_RETRIEVER, REGISTRY_COORDINATOR};
+use eigen_client_avsregistry::reader::{AvsRegistryChainReader, AvsRegistryReader};
+use eigen_testing_utils::m2_holesky_constants::{
+    CHAIN_ID, OPERATOR_STATE_RETRIEVER, REGISTRY_COORDINATOR,
+};
 use eyre::Result;
 
 #[tokio::main]
@@ -11,6 +13,7 @@ async fn main() -> Result<()> {
         REGISTRY_COORDINATOR,
         OPERATOR_STATE_RETRIEVER,
         holesky_provider.to_string(),
+        Some(CHAIN_ID),
     )
     .await
     .expect("failed to build avs registry chain reader");
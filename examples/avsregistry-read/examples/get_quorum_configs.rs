@@ -0,0 +1,26 @@
+//! get_quorum_configs
+use eigen_client_avsregistry::reader::{AvsRegistryChainReader, AvsRegistryReader};
+use eigen_testing_utils::m2_holesky_constants::{
+    CHAIN_ID, OPERATOR_STATE_RETRIEVER, REGISTRY_COORDINATOR,
+};
+use eyre::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let holesky_provider = "https://holesky.drpc.org";
+    let avs_registry = AvsRegistryChainReader::new(
+        REGISTRY_COORDINATOR,
+        OPERATOR_STATE_RETRIEVER,
+        holesky_provider.to_string(),
+        Some(CHAIN_ID),
+    )
+    .await
+    .expect("failed to build avs registry chain reader");
+
+    let quorum_configs = avs_registry.get_quorum_configs().await.unwrap();
+
+    for config in quorum_configs {
+        println!("quorum {} config is :{:?}", config.quorum_number, config);
+    }
+    Ok(())
+}
@@ -17,15 +17,19 @@ async fn main() {
         address!("53012C69A189cfA2D9d29eb6F19B32e0A2EA3490"),
         address!("B4baAfee917fb4449f5ec64804217bccE9f46C67"),
         HOLESKY_PROVIDER.to_string(),
+        Some(17000),
     )
     .await
     .expect("failed to build avs registry chain reader");
-    let avs_registry_subscriber = AvsRegistryChainSubscriber::new(WS_HOLESKY_PROIVIDER.to_string());
+    let avs_registry_subscriber = AvsRegistryChainSubscriber::new(
+        HOLESKY_PROVIDER.to_string(),
+        WS_HOLESKY_PROIVIDER.to_string(),
+    );
 
     let operators_info = Arc::new(Mutex::new(
         OperatorInfoServiceInMemory::new(
             avs_registry_subscriber,
-            avs_registry_chain_reader,
+            Arc::new(avs_registry_chain_reader),
             WS_HOLESKY_PROIVIDER.to_string(),
         )
         .await,
@@ -14,3 +14,6 @@ pub mod m2_holesky_constants;
 
 /// Anvil constants
 pub mod anvil_constants;
+
+/// Mock json-rpc node
+pub mod mock_rpc;
@@ -0,0 +1,35 @@
+//! Mock json-rpc node for unit tests of code talking to a provider.
+use serde_json::{json, Value};
+use wiremock::{matchers::method, Mock, MockServer, Request, Respond, ResponseTemplate};
+
+/// Answers json-rpc requests with the canned result or error of their method, echoing
+/// the id.
+struct JsonRpcResponder(Vec<(&'static str, Result<Value, Value>)>);
+
+impl Respond for JsonRpcResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let request: Value = serde_json::from_slice(&request.body).expect("invalid json-rpc");
+        let mut response = json!({"jsonrpc": "2.0", "id": request["id"]});
+        match self
+            .0
+            .iter()
+            .find(|(method, _)| request["method"] == *method)
+        {
+            Some((_, Ok(result))) => response["result"] = result.clone(),
+            Some((_, Err(error))) => response["error"] = error.clone(),
+            None => response["result"] = Value::Null,
+        }
+        ResponseTemplate::new(200).set_body_json(response)
+    }
+}
+
+/// Starts a mock json-rpc node answering `method` with the result, or the error
+/// object, of every pair in `responses`, and `null` for any other method.
+pub async fn mock_rpc(responses: Vec<(&'static str, Result<Value, Value>)>) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(JsonRpcResponder(responses))
+        .mount(&server)
+        .await;
+    server
+}